@@ -5,16 +5,30 @@ use std::fmt::Write as _;
 mod rich_text_span;
 #[path = "advanced_text/rich_text_style.rs"]
 mod rich_text_style;
+#[path = "advanced_text/text_atlas_allocation.rs"]
+mod text_atlas_allocation;
+#[path = "advanced_text/text_atlas_checker_options.rs"]
+mod text_atlas_checker_options;
+#[path = "advanced_text/text_atlas_growth_policy.rs"]
+mod text_atlas_growth_policy;
 #[path = "advanced_text/text_atlas_page_data.rs"]
 mod text_atlas_page_data;
 #[path = "advanced_text/text_atlas_page_snapshot.rs"]
 mod text_atlas_page_snapshot;
 #[path = "advanced_text/text_atlas_quad.rs"]
 mod text_atlas_quad;
+#[path = "advanced_text/text_atlas_residency.rs"]
+mod text_atlas_residency;
 #[path = "advanced_text/text_atlas_sampling.rs"]
 mod text_atlas_sampling;
+#[path = "advanced_text/text_caret_height.rs"]
+mod text_caret_height;
 #[path = "advanced_text/text_color.rs"]
 mod text_color;
+#[path = "advanced_text/text_color_alpha_mode.rs"]
+mod text_color_alpha_mode;
+#[path = "advanced_text/text_color_glyph_filter.rs"]
+mod text_color_glyph_filter;
 #[path = "advanced_text/text_feature_setting.rs"]
 mod text_feature_setting;
 #[path = "advanced_text/text_frame_info.rs"]
@@ -23,6 +37,10 @@ mod text_frame_info;
 mod text_frame_output;
 #[path = "advanced_text/text_fundamentals.rs"]
 mod text_fundamentals;
+#[path = "advanced_text/text_glyph_content.rs"]
+mod text_glyph_content;
+#[path = "advanced_text/text_glyph_draw_info.rs"]
+mod text_glyph_draw_info;
 #[path = "advanced_text/text_glyph_raster_mode.rs"]
 mod text_glyph_raster_mode;
 #[path = "advanced_text/text_gpu_power_preference.rs"]
@@ -96,15 +114,24 @@ mod vector_text_shape;
 
 pub use self::rich_text_span::RichTextSpan;
 pub use self::rich_text_style::RichTextStyle;
+pub use self::text_atlas_allocation::TextAtlasAllocation;
+pub use self::text_atlas_checker_options::TextAtlasCheckerOptions;
+pub use self::text_atlas_growth_policy::TextAtlasGrowthPolicy;
 pub use self::text_atlas_page_data::TextAtlasPageData;
 pub use self::text_atlas_page_snapshot::TextAtlasPageSnapshot;
 pub use self::text_atlas_quad::TextAtlasQuad;
+pub use self::text_atlas_residency::TextAtlasResidency;
 pub use self::text_atlas_sampling::TextAtlasSampling;
+pub use self::text_caret_height::TextCaretHeight;
 pub use self::text_color::TextColor;
+pub use self::text_color_alpha_mode::TextColorAlphaMode;
+pub use self::text_color_glyph_filter::TextColorGlyphFilter;
 pub use self::text_feature_setting::TextFeatureSetting;
 pub use self::text_frame_info::TextFrameInfo;
 pub use self::text_frame_output::TextFrameOutput;
 pub use self::text_fundamentals::TextFundamentals;
+pub use self::text_glyph_content::TextGlyphContent;
+pub use self::text_glyph_draw_info::TextGlyphDrawInfo;
 pub use self::text_glyph_raster_mode::TextGlyphRasterMode;
 pub use self::text_gpu_power_preference::TextGpuPowerPreference;
 pub use self::text_gpu_quad::TextGpuQuad;
@@ -0,0 +1,12 @@
+use super::*;
+
+/// A resident glyph's rectangle within an atlas page, as returned by
+/// [`crate::TextUi::atlas_sorted_allocations`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct TextAtlasAllocation {
+    pub page_index: usize,
+    /// Top-left corner in page pixels, excluding padding.
+    pub min_px: [usize; 2],
+    pub size_px: [usize; 2],
+    pub content: TextGlyphContent,
+}
@@ -0,0 +1,21 @@
+use super::*;
+
+/// Checkerboard used behind atlas page snapshots so transparent texels stay visible when
+/// inspecting packing, gutters, and glyph alpha.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TextAtlasCheckerOptions {
+    /// Edge length of one checker cell in atlas pixels. Clamped to at least 1.
+    pub size_px: usize,
+    pub color_a: TextColor,
+    pub color_b: TextColor,
+}
+
+impl Default for TextAtlasCheckerOptions {
+    fn default() -> Self {
+        Self {
+            size_px: 8,
+            color_a: TextColor::from_rgba8(64, 64, 64, 255),
+            color_b: TextColor::from_rgba8(96, 96, 96, 255),
+        }
+    }
+}
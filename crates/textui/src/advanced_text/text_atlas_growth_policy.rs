@@ -0,0 +1,11 @@
+/// When the glyph atlas may allocate another page instead of evicting glyphs.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum TextAtlasGrowthPolicy {
+    #[default]
+    Grow,
+    /// Stops adding pages once this many exist and evicts least recently used glyphs instead,
+    /// bounding atlas memory on constrained devices.
+    GrowUpTo(usize),
+    /// Keeps the first page of each content kind and only evicts from then on.
+    EvictOnly,
+}
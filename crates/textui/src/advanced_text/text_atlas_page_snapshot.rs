@@ -22,4 +22,33 @@ impl TextAtlasPageSnapshot {
             rgba8: Arc::from(self.rgba8.as_slice()),
         }
     }
+
+    /// Returns an opaque copy of this page composited over a checkerboard.
+    ///
+    /// Atlas texels are premultiplied, so this is a plain source-over blend.
+    pub fn composited_over_checker(&self, options: &TextAtlasCheckerOptions) -> Self {
+        let cell = options.size_px.max(1);
+        let width = self.size_px[0].max(1);
+        let mut rgba8 = Vec::with_capacity(self.rgba8.len());
+        for (index, texel) in self.rgba8.chunks_exact(4).enumerate() {
+            let (x, y) = (index % width, index / width);
+            let checker = if (x / cell + y / cell) % 2 == 0 {
+                options.color_a
+            } else {
+                options.color_b
+            }
+            .to_array();
+            let inv_alpha = 255 - u16::from(texel[3]);
+            for channel in 0..3 {
+                let under = (u16::from(checker[channel]) * inv_alpha + 127) / 255;
+                rgba8.push((u16::from(texel[channel]) + under).min(255) as u8);
+            }
+            rgba8.push(255);
+        }
+        Self {
+            page_index: self.page_index,
+            size_px: self.size_px,
+            rgba8,
+        }
+    }
 }
@@ -0,0 +1,14 @@
+/// Result of [`crate::TextUi::atlas_residency_at_scale`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct TextAtlasResidency {
+    /// Glyphs that are already rasterized into an atlas page.
+    pub resident: usize,
+    /// Glyphs that would need to be rasterized before they can be painted.
+    pub missing: usize,
+}
+
+impl TextAtlasResidency {
+    pub const fn total(self) -> usize {
+        self.resident + self.missing
+    }
+}
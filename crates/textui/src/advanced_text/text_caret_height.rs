@@ -0,0 +1,9 @@
+/// How tall the editor caret is drawn.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum TextCaretHeight {
+    /// Spans the whole line box, leading included.
+    #[default]
+    LineHeight,
+    /// Spans only the font size, centred in the line box like the glyphs are.
+    TextExtent,
+}
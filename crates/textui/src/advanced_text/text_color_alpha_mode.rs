@@ -0,0 +1,9 @@
+/// How color-font bitmaps encode alpha. swash hands back straight alpha for its color
+/// sources, but some embedded bitmap strikes arrive already premultiplied and would otherwise
+/// be multiplied twice, darkening their translucent edges.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum TextColorAlphaMode {
+    #[default]
+    Straight,
+    Premultiplied,
+}
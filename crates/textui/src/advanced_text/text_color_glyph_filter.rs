@@ -0,0 +1,11 @@
+use super::*;
+
+/// Recolors color-font glyphs (emoji, COLR/bitmap faces) at rasterization time so they match a
+/// stylized theme. Alpha-mask glyphs are tinted normally and are not affected.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TextColorGlyphFilter {
+    Grayscale,
+    Sepia,
+    /// Maps luminance onto this color.
+    Tint(TextColor),
+}
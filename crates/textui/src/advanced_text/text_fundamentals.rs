@@ -15,6 +15,18 @@ pub struct TextFundamentals {
     pub letter_spacing_points: f32,
     pub word_spacing_points: f32,
     pub letter_spacing_floor: f32,
+    /// Stretches word gaps so wrapped lines fill the wrap width. The last line of each
+    /// paragraph stays start-aligned. Has no effect without a wrap width.
+    pub justify: bool,
+    /// Sets laid-out lines as top-to-bottom columns that advance right to left, keeping glyphs
+    /// upright, for CJK vertical writing. The wrap width becomes the column height and the
+    /// font's `vert` alternates are enabled.
+    pub vertical: bool,
+    /// Overrides [`TextRasterizationConfig::hinting`] for this text, e.g. to disable hinting
+    /// for large display type. `None` inherits the configured mode, while `Some(Auto)` picks by
+    /// display scale even when the configured mode is forced. Glyphs rasterized with different
+    /// hinting are cached apart.
+    pub hinting: Option<TextHintingMode>,
     pub feature_settings: Vec<TextFeatureSetting>,
     pub variation_settings: Vec<TextVariationSetting>,
 }
@@ -35,6 +47,9 @@ impl Default for TextFundamentals {
             letter_spacing_points: 0.0,
             word_spacing_points: 0.0,
             letter_spacing_floor: -0.5,
+            justify: false,
+            vertical: false,
+            hinting: None,
             feature_settings: Vec::new(),
             variation_settings: Vec::new(),
         }
@@ -0,0 +1,10 @@
+/// How a cached atlas glyph was rasterized, for diagnosing rendering problems.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TextGlyphContent {
+    AlphaMask,
+    /// Per-channel coverage; the text color tints each channel separately.
+    SubpixelMask,
+    Color,
+    Sdf,
+    Msdf,
+}
@@ -0,0 +1,13 @@
+use super::*;
+
+/// One glyph emitted while building an atlas scene, recorded while
+/// [`crate::TextUi::set_glyph_draw_logging`] is enabled.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextGlyphDrawInfo {
+    /// Quad bounds in points, relative to the origin of the text being built or painted.
+    pub rect: TextRect,
+    /// `false` when the glyph had to be rasterized for this draw.
+    pub atlas_hit: bool,
+    /// Glyph atlas page, or the scene's own page for GPU scenes built for labels.
+    pub atlas_page_index: usize,
+}
@@ -8,4 +8,33 @@ pub struct TextGpuScene {
     pub bounds_max: [f32; 2],
     pub size_points: [f32; 2],
     pub fingerprint: u64,
+    /// `true` when any quad samples a color (emoji) glyph rather than a tinted mask, for
+    /// callers that need to warn before exporting to monochrome targets.
+    pub has_color_glyphs: bool,
+}
+
+impl TextGpuScene {
+    /// Quads whose bounds overlap `clip_rect`, for callers issuing their own draw calls (for
+    /// example from an egui paint callback) who want to skip glyphs outside the visible area.
+    /// An unbounded clip (e.g. `Rect::EVERYTHING`) yields every quad without per-quad tests.
+    pub fn quads_intersecting(&self, clip_rect: TextRect) -> impl Iterator<Item = &TextGpuQuad> {
+        let unbounded = clip_rect.min.x == f32::NEG_INFINITY
+            && clip_rect.min.y == f32::NEG_INFINITY
+            && clip_rect.max.x == f32::INFINITY
+            && clip_rect.max.y == f32::INFINITY;
+        self.quads.iter().filter(move |quad| {
+            if unbounded {
+                return true;
+            }
+            let (mut min, mut max) = ([f32::INFINITY; 2], [f32::NEG_INFINITY; 2]);
+            for point in quad.positions {
+                min = [min[0].min(point[0]), min[1].min(point[1])];
+                max = [max[0].max(point[0]), max[1].max(point[1])];
+            }
+            max[0] > clip_rect.min.x
+                && min[0] < clip_rect.max.x
+                && max[1] > clip_rect.min.y
+                && min[1] < clip_rect.max.y
+        })
+    }
 }
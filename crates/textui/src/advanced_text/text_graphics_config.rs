@@ -6,6 +6,7 @@ pub struct TextGraphicsConfig {
     pub atlas_sampling: TextAtlasSampling,
     pub atlas_page_target_px: usize,
     pub atlas_padding_px: usize,
+    pub atlas_growth_policy: TextAtlasGrowthPolicy,
     pub graphics_api: TextGraphicsApi,
     pub gpu_power_preference: TextGpuPowerPreference,
     pub rasterization: TextRasterizationConfig,
@@ -24,6 +25,7 @@ impl Default for TextGraphicsConfig {
             atlas_sampling: TextAtlasSampling::Linear,
             atlas_page_target_px: 1024,
             atlas_padding_px: 1,
+            atlas_growth_policy: TextAtlasGrowthPolicy::Grow,
             graphics_api: TextGraphicsApi::Auto,
             gpu_power_preference: TextGpuPowerPreference::Auto,
             rasterization: TextRasterizationConfig::default(),
@@ -13,4 +13,48 @@ impl TextPath {
             closed: false,
         }
     }
+
+    /// Flattens a quadratic Bézier curve into `segments` straight pieces.
+    pub fn quadratic(
+        start: TextPoint,
+        control: TextPoint,
+        end: TextPoint,
+        segments: usize,
+    ) -> Self {
+        Self::from_curve(segments, |t| {
+            let u = 1.0 - t;
+            let (a, b, c) = (u * u, 2.0 * u * t, t * t);
+            TextPoint::new(
+                a * start.x + b * control.x + c * end.x,
+                a * start.y + b * control.y + c * end.y,
+            )
+        })
+    }
+
+    /// Flattens a cubic Bézier curve into `segments` straight pieces.
+    pub fn cubic(
+        start: TextPoint,
+        control_a: TextPoint,
+        control_b: TextPoint,
+        end: TextPoint,
+        segments: usize,
+    ) -> Self {
+        Self::from_curve(segments, |t| {
+            let u = 1.0 - t;
+            let (a, b, c, d) = (u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t);
+            TextPoint::new(
+                a * start.x + b * control_a.x + c * control_b.x + d * end.x,
+                a * start.y + b * control_a.y + c * control_b.y + d * end.y,
+            )
+        })
+    }
+
+    fn from_curve(segments: usize, point_at: impl Fn(f32) -> TextPoint) -> Self {
+        let segments = segments.max(1);
+        Self::new(
+            (0..=segments)
+                .map(|i| point_at(i as f32 / segments as f32))
+                .collect::<Vec<_>>(),
+        )
+    }
 }
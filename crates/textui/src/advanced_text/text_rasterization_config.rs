@@ -10,6 +10,25 @@ pub struct TextRasterizationConfig {
     pub stem_darkening_min_ppem: f32,
     pub stem_darkening_max_ppem: f32,
     pub stem_darkening_max_strength: f32,
+    /// Rounds atlas raster sizes to multiples of this many pixels and stretches the quad back
+    /// to the exact size, bounding atlas variants while font size animates. `None` rasterizes
+    /// every fractional size.
+    pub size_quantization_px: Option<f32>,
+    /// Exponent applied to alpha-mask coverage before tinting, for light text on dark
+    /// backgrounds. Values above `1.0` thin stems that otherwise bloom; `None` keeps raw
+    /// coverage.
+    pub dark_mode_contrast: Option<f32>,
+    /// Recolors color glyphs such as emoji; `None` keeps their native colors.
+    pub color_glyph_filter: Option<TextColorGlyphFilter>,
+    /// Scales color glyph quads about their baseline, e.g. below `1.0` when emoji look
+    /// oversized next to text of the same font size. Mask glyphs and advances are unaffected.
+    pub color_glyph_scale: f32,
+    /// Alpha encoding assumed for color glyph bitmaps before they are stored in the atlas.
+    pub color_alpha_mode: TextColorAlphaMode,
+    /// Alpha-mask glyphs whose peak coverage stays below this fraction (`0.0..=1.0`) are
+    /// skipped instead of stored, dropping hairlines that vanish at tiny sizes. `None` keeps
+    /// every glyph.
+    pub min_mask_coverage: Option<f32>,
 }
 
 impl Default for TextRasterizationConfig {
@@ -23,6 +42,12 @@ impl Default for TextRasterizationConfig {
             stem_darkening_min_ppem: 14.0,
             stem_darkening_max_ppem: 28.0,
             stem_darkening_max_strength: 0.22,
+            size_quantization_px: None,
+            dark_mode_contrast: None,
+            color_glyph_filter: None,
+            color_glyph_scale: 1.0,
+            color_alpha_mode: TextColorAlphaMode::Straight,
+            min_mask_coverage: None,
         }
     }
 }
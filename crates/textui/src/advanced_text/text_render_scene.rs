@@ -1,4 +1,5 @@
 use super::*;
+use crate::gpu::{quad_positions_from_min_size, rect_from_points, uv_quad_points};
 
 #[derive(Clone, Debug)]
 pub struct TextRenderScene {
@@ -19,6 +20,147 @@ impl TextRenderScene {
         page_indices
     }
 
+    /// Clips each visual row to its own rect so one scene can fill several table cells. A
+    /// quad belongs to the first rect whose vertical span holds its centre; quads outside every
+    /// rect are dropped. Axis-aligned quads are trimmed with their UVs adjusted, while rotated
+    /// (path) quads are kept or dropped whole.
+    pub fn clipped_to_rows(&self, row_clips: &[TextRect]) -> Self {
+        let mut bounds: Option<Rect> = None;
+        let quads = self
+            .quads
+            .iter()
+            .filter_map(|quad| {
+                let positions = quad.positions.map(Pos2::from);
+                let quad_rect = rect_from_points(positions);
+                let center = quad_rect.center();
+                let clip = Rect::from(
+                    *row_clips
+                        .iter()
+                        .find(|clip| center.y >= clip.min.y && center.y < clip.max.y)?,
+                );
+                let axis_aligned =
+                    positions[0].y == positions[1].y && positions[0].x == positions[3].x;
+                if !axis_aligned {
+                    if !clip.contains(center) {
+                        return None;
+                    }
+                    bounds = Some(bounds.map_or(quad_rect, |current| current.union(quad_rect)));
+                    return Some(*quad);
+                }
+                let clipped = quad_rect.intersect(clip);
+                if !clipped.is_positive() {
+                    return None;
+                }
+                let uv_min = Pos2::from(quad.uvs[0]);
+                let uv_max = Pos2::from(quad.uvs[2]);
+                let uv_at = |point: Pos2| {
+                    Pos2::new(
+                        egui::lerp(
+                            uv_min.x..=uv_max.x,
+                            (point.x - quad_rect.min.x) / quad_rect.width(),
+                        ),
+                        egui::lerp(
+                            uv_min.y..=uv_max.y,
+                            (point.y - quad_rect.min.y) / quad_rect.height(),
+                        ),
+                    )
+                };
+                let uv = Rect::from_min_max(uv_at(clipped.min), uv_at(clipped.max));
+                bounds = Some(bounds.map_or(clipped, |current| current.union(clipped)));
+                Some(TextAtlasQuad {
+                    positions: quad_positions_from_min_size(clipped.min, clipped.size())
+                        .map(Into::into),
+                    uvs: uv_quad_points(uv).map(Into::into),
+                    ..*quad
+                })
+            })
+            .collect::<Vec<_>>();
+        Self {
+            quads,
+            bounds: bounds.unwrap_or(Rect::NOTHING).into(),
+            size_points: self.size_points,
+        }
+    }
+
+    /// Blends tintable glyphs toward `highlight` inside a soft band `band_width_points` wide
+    /// that sweeps left to right across the scene as `phase` goes from `0.0` to `1.0`, for
+    /// loading shimmer effects. Each glyph takes the gradient value at its centre.
+    pub fn with_shimmer(&self, highlight: TextColor, band_width_points: f32, phase: f32) -> Self {
+        let band_half = band_width_points.max(f32::EPSILON) * 0.5;
+        let bounds = Rect::from(self.bounds);
+        let sweep_start = bounds.min.x - band_half;
+        let sweep_end = bounds.max.x + band_half;
+        let band_center = egui::lerp(sweep_start..=sweep_end, phase.rem_euclid(1.0));
+        let highlight = Color32::from(highlight);
+        let mut scene = self.clone();
+        for quad in scene.quads.iter_mut().filter(|quad| !quad.is_color) {
+            let center_x = rect_from_points(quad.positions.map(Pos2::from)).center().x;
+            let t = (1.0 - (center_x - band_center).abs() / band_half).clamp(0.0, 1.0);
+            quad.tint = Color32::from(quad.tint)
+                .lerp_to_gamma(highlight, t * t * (3.0 - 2.0 * t))
+                .into();
+        }
+        scene
+    }
+
+    /// Scales each glyph quad about its own centre by `scale_for(quad_index)`, for wave or
+    /// bounce animations. Glyph positions and advances are untouched, so the baseline layout
+    /// holds while letters pulse; the bounds grow to cover enlarged glyphs.
+    pub fn with_glyph_scale(&self, mut scale_for: impl FnMut(usize) -> f32) -> Self {
+        let mut scene = self.clone();
+        let mut bounds = Rect::from(scene.bounds);
+        for (index, quad) in scene.quads.iter_mut().enumerate() {
+            let scale = scale_for(index).max(0.0);
+            let center = rect_from_points(quad.positions.map(Pos2::from)).center();
+            quad.positions = quad
+                .positions
+                .map(|point| (center + (Pos2::from(point) - center) * scale).into());
+            bounds = bounds.union(rect_from_points(quad.positions.map(Pos2::from)));
+        }
+        scene.bounds = bounds.into();
+        scene
+    }
+
+    /// Mirrors the scene vertically about `axis_y` (usually the baseline or the bottom of the
+    /// bounds) for reflection effects. Glyph opacity falls off linearly with distance below
+    /// the axis and reaches zero at `fade_distance_points`; fully faded glyphs are dropped.
+    pub fn mirrored(&self, axis_y: f32, fade_distance_points: f32) -> Self {
+        let fade_distance = fade_distance_points.max(f32::EPSILON);
+        let mut bounds: Option<Rect> = None;
+        let quads = self
+            .quads
+            .iter()
+            .filter_map(|quad| {
+                let positions = quad
+                    .positions
+                    .map(|point| TextPoint::new(point.x, 2.0 * axis_y - point.y));
+                let rect = rect_from_points(positions.map(Pos2::from));
+                let opacity = 1.0 - (rect.center().y - axis_y).max(0.0) / fade_distance;
+                if opacity <= 0.0 {
+                    return None;
+                }
+                bounds = Some(bounds.map_or(rect, |bounds| bounds.union(rect)));
+                Some(TextAtlasQuad {
+                    positions,
+                    tint: Color32::from(quad.tint)
+                        .gamma_multiply(opacity.min(1.0))
+                        .into(),
+                    ..*quad
+                })
+            })
+            .collect();
+        Self {
+            quads,
+            bounds: bounds.unwrap_or(Rect::NOTHING).into(),
+            size_points: self.size_points,
+        }
+    }
+
+    /// Whether any quad draws a color (emoji) glyph.
+    pub fn has_color_glyphs(&self) -> bool {
+        self.quads.iter().any(|quad| quad.is_color)
+    }
+
     pub fn to_gpu_scene(&self, atlas_pages: Vec<TextAtlasPageData>) -> TextGpuScene {
         TextGpuScene {
             atlas_pages,
@@ -36,6 +178,7 @@ impl TextRenderScene {
             bounds_max: [self.bounds.max.x, self.bounds.max.y],
             size_points: [self.size_points.x, self.size_points.y],
             fingerprint: 0,
+            has_color_glyphs: self.has_color_glyphs(),
         }
     }
 }
@@ -32,35 +32,66 @@ pub(super) struct GlyphRasterKey {
 
 impl GlyphRasterKey {
     const STEM_DARKENING: u8 = 1 << 0;
+    const HINTING_ENABLED: u8 = 1 << 1;
+    const HINTING_DISABLED: u8 = 1 << 2;
+    const HINTING_AUTO: u8 = 1 << 3;
 
     #[inline]
     pub(super) fn new(
         cache_key: CacheKey,
         display_scale: f32,
         stem_darkening: bool,
+        hinting: Option<TextHintingMode>,
         content_mode: GlyphContentMode,
         field_range_px: f32,
         variation_settings: Arc<[TextVariationSetting]>,
     ) -> Self {
+        let stem_darkening_flag = if stem_darkening {
+            Self::STEM_DARKENING
+        } else {
+            0
+        };
+        let hinting_flag = match hinting {
+            Some(TextHintingMode::Enabled) => Self::HINTING_ENABLED,
+            Some(TextHintingMode::Disabled) => Self::HINTING_DISABLED,
+            Some(TextHintingMode::Auto) => Self::HINTING_AUTO,
+            None => 0,
+        };
         Self {
             cache_key,
             display_scale_bits: display_scale.to_bits(),
-            raster_flags: if stem_darkening {
-                Self::STEM_DARKENING
-            } else {
-                0
-            },
+            raster_flags: stem_darkening_flag | hinting_flag,
             content_mode,
             field_range_bits: field_range_px.to_bits(),
             variation_settings,
         }
     }
 
+    #[inline]
+    pub(super) fn font_id(&self) -> fontdb::ID {
+        self.cache_key.font_id
+    }
+
     #[inline]
     pub(super) fn display_scale(&self) -> f32 {
         f32::from_bits(self.display_scale_bits)
     }
 
+    /// The hinting mode requested for this glyph, or `configured` when the text did not
+    /// override it.
+    #[inline]
+    pub(super) fn hinting_or(&self, configured: TextHintingMode) -> TextHintingMode {
+        if self.raster_flags & Self::HINTING_ENABLED != 0 {
+            TextHintingMode::Enabled
+        } else if self.raster_flags & Self::HINTING_DISABLED != 0 {
+            TextHintingMode::Disabled
+        } else if self.raster_flags & Self::HINTING_AUTO != 0 {
+            TextHintingMode::Auto
+        } else {
+            configured
+        }
+    }
+
     #[inline]
     pub(super) fn stem_darkening(&self) -> bool {
         self.raster_flags & Self::STEM_DARKENING != 0
@@ -90,6 +121,22 @@ impl GlyphRasterKey {
         }
         key
     }
+
+    /// Snaps the raster size to a multiple of `step_px`, returning the key along with the
+    /// factor that scales the quantized bitmap back to the requested size.
+    pub(super) fn quantized(self, step_px: Option<f32>) -> (Self, f32) {
+        let Some(step_px) = step_px.filter(|step| step.is_finite() && *step > 0.0) else {
+            return (self, 1.0);
+        };
+        let ppem = f32::from_bits(self.cache_key.font_size_bits);
+        let quantized_ppem = ((ppem / step_px).round() * step_px).max(step_px);
+        if quantized_ppem == ppem {
+            return (self, 1.0);
+        }
+        let mut key = self;
+        key.cache_key.font_size_bits = quantized_ppem.to_bits();
+        (key, ppem / quantized_ppem)
+    }
 }
 
 #[inline]
@@ -111,6 +158,7 @@ pub(super) struct GlyphAtlas {
     pages: Vec<GlyphAtlasPage>,
     page_side_px: usize,
     padding_px: usize,
+    growth_policy: TextAtlasGrowthPolicy,
     sampling: TextAtlasSampling,
     rasterization: TextRasterizationConfig,
     /// Kept to coordinate atlas recreation when the broader text rendering
@@ -118,7 +166,25 @@ pub(super) struct GlyphAtlas {
     linear_pipeline: bool,
     pub(super) wgpu_render_state: Option<EguiWgpuRenderState>,
     pub(super) pending: FxHashSet<GlyphRasterKey>,
+    /// Keys skipped for falling under `min_mask_coverage`, so they are rasterized only once.
+    below_min_coverage: FxHashSet<GlyphRasterKey>,
     ready: VecDeque<GlyphAtlasWorkerResponse>,
+    /// RGBA16F encode buffer reused across native page uploads.
+    upload_scratch: Vec<u8>,
+    /// When set, synchronously rasterized glyphs only grow their page's dirty rect and are
+    /// uploaded together by [`Self::flush_uploads`].
+    coalesce_uploads: bool,
+    /// Largest rasterized glyph offered to the atlas since creation, kept across clears.
+    largest_glyph_px: [usize; 2],
+    /// Also shields glyphs used in the previous frame from LRU eviction, for compositors that
+    /// may still display the previous frame's quads.
+    protect_previous_frame: bool,
+    thrash_warnings: bool,
+    /// LRU evictions forced by allocation pressure since the last frame check.
+    frame_evictions: usize,
+    /// Consecutive frames whose evictions reached `GLYPH_ATLAS_THRASH_EVICTIONS_PER_FRAME`.
+    thrash_streak_frames: u32,
+    last_thrash_warning_frame: Option<u64>,
     generation: u64,
     tx: Option<mpsc::Sender<GlyphAtlasWorkerMessage>>,
     rx: Option<mpsc::Receiver<GlyphAtlasWorkerResponse>>,
@@ -132,6 +198,8 @@ pub(super) struct GlyphAtlasPage {
     cached_page_data: Mutex<Option<TextAtlasPageData>>,
     dirty_rect: Option<DirtyAtlasRect>,
     live_glyphs: usize,
+    /// Bumped on every write to `backing`, so CPU-side consumers can poll for changes.
+    revision: u64,
 }
 
 #[derive(Clone, Debug)]
@@ -143,11 +211,32 @@ pub(super) struct GlyphAtlasEntry {
     placement_left_px: i32,
     placement_top_px: i32,
     is_color: bool,
+    subpixel_mask: bool,
     content_mode: GlyphContentMode,
     last_used_frame: u64,
     approx_bytes: usize,
 }
 
+impl GlyphAtlasEntry {
+    fn content(&self) -> TextGlyphContent {
+        glyph_content(self.content_mode, self.is_color, self.subpixel_mask)
+    }
+}
+
+fn glyph_content(
+    content_mode: GlyphContentMode,
+    is_color: bool,
+    subpixel_mask: bool,
+) -> TextGlyphContent {
+    match content_mode {
+        GlyphContentMode::Sdf => TextGlyphContent::Sdf,
+        GlyphContentMode::Msdf => TextGlyphContent::Msdf,
+        GlyphContentMode::AlphaMask if is_color => TextGlyphContent::Color,
+        GlyphContentMode::AlphaMask if subpixel_mask => TextGlyphContent::SubpixelMask,
+        GlyphContentMode::AlphaMask => TextGlyphContent::AlphaMask,
+    }
+}
+
 #[derive(Clone)]
 pub(super) struct ResolvedGlyphAtlasEntry {
     pub(super) page_index: usize,
@@ -166,10 +255,37 @@ pub(super) struct PreparedAtlasGlyph {
     pub(super) placement_left_px: i32,
     pub(super) placement_top_px: i32,
     pub(super) is_color: bool,
+    pub(super) subpixel_mask: bool,
     pub(super) content_mode: GlyphContentMode,
     pub(super) approx_bytes: usize,
 }
 
+impl PreparedAtlasGlyph {
+    /// Placeholder for a mask whose peak coverage fell below
+    /// [`TextRasterizationConfig::min_mask_coverage`]. Real glyphs with an empty placement are
+    /// rejected before this point, so a zero size marks the placeholder unambiguously.
+    fn below_min_coverage() -> Self {
+        Self {
+            upload_image: ColorImage::filled([0, 0], Color32::TRANSPARENT),
+            size_px: [0, 0],
+            placement_left_px: 0,
+            placement_top_px: 0,
+            is_color: false,
+            subpixel_mask: false,
+            content_mode: GlyphContentMode::AlphaMask,
+            approx_bytes: 0,
+        }
+    }
+
+    pub(super) fn is_below_min_coverage(&self) -> bool {
+        self.size_px == [0, 0]
+    }
+
+    pub(super) fn content(&self) -> TextGlyphContent {
+        glyph_content(self.content_mode, self.is_color, self.subpixel_mask)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub(super) struct PaintTextQuad {
     pub(super) page_index: usize,
@@ -193,6 +309,9 @@ pub(super) fn hash_text_fundamentals<H: Hasher>(fundamentals: &TextFundamentals,
     fundamentals.letter_spacing_points.to_bits().hash(state);
     fundamentals.word_spacing_points.to_bits().hash(state);
     fundamentals.letter_spacing_floor.to_bits().hash(state);
+    fundamentals.justify.hash(state);
+    fundamentals.vertical.hash(state);
+    fundamentals.hinting.hash(state);
     fundamentals.feature_settings.len().hash(state);
     for feature in &fundamentals.feature_settings {
         feature.hash(state);
@@ -414,6 +533,7 @@ pub(super) fn collect_prepared_glyphs_from_buffer(
                     physical.cache_key,
                     scale,
                     fundamentals.stem_darkening,
+                    fundamentals.hinting,
                     GlyphContentMode::AlphaMask,
                     0.0,
                     Arc::clone(&variation_settings),
@@ -439,6 +559,70 @@ pub(super) fn collect_prepared_glyphs_from_buffer(
     (glyphs, max_line_extra_points)
 }
 
+/// Vertical counterpart of [`collect_prepared_glyphs_from_buffer`]: each layout run becomes a
+/// column one line-height wide, starting at the right edge, and each glyph is centred in its
+/// column with its horizontal advance reused as the vertical one. Returns the glyphs and the
+/// size of the column block in points.
+pub(super) fn collect_vertical_prepared_glyphs_from_buffer(
+    buffer: &Buffer,
+    scale: f32,
+    default_color: Color32,
+    fundamentals: &TextFundamentals,
+) -> (Vec<PreparedGlyph>, Vec2) {
+    let mut glyphs = Vec::new();
+    let variation_settings = shared_variation_settings(fundamentals);
+    let mut columns_width_points = 0.0_f32;
+    let mut max_column_height_points = 0.0_f32;
+
+    for run in buffer.layout_runs() {
+        let column_width_points = run.line_height / scale;
+        let column_center_points = -(columns_width_points + column_width_points * 0.5);
+        // Centres the line box of the horizontal layout on each glyph's vertical cell.
+        let baseline_in_cell_px = (run.line_y - run.line_top) - run.line_height * 0.5;
+        let mut column_extra_points = 0.0;
+
+        for (glyph_index, glyph) in run.glyphs.iter().enumerate() {
+            let physical = glyph.physical((0.0, 0.0), 1.0);
+            let cell_top_points = physical.x as f32 / scale + column_extra_points;
+            glyphs.push(PreparedGlyph {
+                cache_key: GlyphRasterKey::new(
+                    physical.cache_key,
+                    scale,
+                    fundamentals.stem_darkening,
+                    fundamentals.hinting,
+                    GlyphContentMode::AlphaMask,
+                    0.0,
+                    Arc::clone(&variation_settings),
+                ),
+                offset_points: egui::vec2(
+                    column_center_points - glyph.w / scale * 0.5,
+                    cell_top_points + (glyph.w * 0.5 + baseline_in_cell_px) / scale,
+                ),
+                color: glyph.color_opt.map_or(default_color, cosmic_to_egui_color),
+            });
+            column_extra_points += spacing_after_glyph_points(
+                run.text,
+                glyph,
+                glyph_index,
+                run.glyphs.len(),
+                fundamentals,
+            );
+        }
+
+        columns_width_points += column_width_points;
+        max_column_height_points =
+            max_column_height_points.max(run.line_w / scale + column_extra_points);
+    }
+
+    for glyph in &mut glyphs {
+        glyph.offset_points.x += columns_width_points;
+    }
+    (
+        glyphs,
+        egui::vec2(columns_width_points, max_column_height_points),
+    )
+}
+
 pub(super) struct GlyphAtlasWorkerResponse {
     generation: u64,
     cache_key: GlyphRasterKey,
@@ -458,12 +642,22 @@ impl GlyphAtlas {
             pages: Vec::new(),
             page_side_px: GLYPH_ATLAS_PAGE_TARGET_PX,
             padding_px: GLYPH_ATLAS_PADDING_PX.max(0) as usize,
+            growth_policy: TextAtlasGrowthPolicy::Grow,
             sampling: TextAtlasSampling::Linear,
             rasterization: TextRasterizationConfig::default(),
             linear_pipeline: false,
             wgpu_render_state: None,
             pending: FxHashSet::default(),
+            below_min_coverage: FxHashSet::default(),
             ready: VecDeque::new(),
+            upload_scratch: Vec::new(),
+            coalesce_uploads: false,
+            largest_glyph_px: [0, 0],
+            protect_previous_frame: false,
+            thrash_warnings: false,
+            frame_evictions: 0,
+            thrash_streak_frames: 0,
+            last_thrash_warning_frame: None,
             generation: 0,
             tx: Some(tx),
             rx: Some(result_rx),
@@ -484,6 +678,7 @@ impl GlyphAtlas {
             self.pending.clear();
             self.ready.clear();
             let _ = self.entries.write(|state| state.clear());
+            self.below_min_coverage.clear();
             self.free_all_pages();
         }
         self.wgpu_render_state = render_state.cloned();
@@ -493,8 +688,89 @@ impl GlyphAtlas {
         self.page_side_px = page_side_px.max(1);
     }
 
+    /// Switches the filter used to sample atlas pages. Existing page textures are re-bound
+    /// with the new filter in place, so cached glyphs survive the change.
     pub(super) fn set_sampling(&mut self, sampling: TextAtlasSampling) {
+        if self.sampling == sampling {
+            return;
+        }
         self.sampling = sampling;
+        let options = texture_options_for_sampling(sampling);
+        for page in &mut self.pages {
+            match &mut page.texture {
+                GlyphAtlasTexture::Egui(texture) => {
+                    texture.set(page.backing.clone(), options);
+                    page.dirty_rect = None;
+                }
+                GlyphAtlasTexture::Wgpu(texture) => {
+                    if let Some(render_state) = self.wgpu_render_state.as_ref() {
+                        let view = texture
+                            .texture
+                            .create_view(&wgpu::TextureViewDescriptor::default());
+                        render_state
+                            .renderer
+                            .write()
+                            .update_egui_texture_from_wgpu_texture(
+                                &render_state.device,
+                                &view,
+                                wgpu_filter_mode_for_sampling(sampling),
+                                texture.id,
+                            );
+                    }
+                }
+            }
+        }
+    }
+
+    pub(super) fn set_coalesce_uploads(&mut self, coalesce_uploads: bool) {
+        if self.coalesce_uploads && !coalesce_uploads {
+            self.flush_dirty_pages();
+        }
+        self.coalesce_uploads = coalesce_uploads;
+    }
+
+    /// Uploads every page's accumulated dirty rect in one call per page.
+    pub(super) fn flush_uploads(&mut self) {
+        self.flush_dirty_pages();
+    }
+
+    pub(super) fn set_protect_previous_frame(&mut self, protect_previous_frame: bool) {
+        self.protect_previous_frame = protect_previous_frame;
+    }
+
+    pub(super) fn set_thrash_warnings(&mut self, thrash_warnings: bool) {
+        self.thrash_warnings = thrash_warnings;
+        self.thrash_streak_frames = 0;
+    }
+
+    /// Closes out the previous frame's eviction count. Called once per frame from
+    /// `TextUi::begin_frame_info`, before stale trimming, which is routine and not counted.
+    pub(super) fn check_thrashing(&mut self, current_frame: u64) {
+        let evictions = mem::take(&mut self.frame_evictions);
+        if !self.thrash_warnings {
+            return;
+        }
+        if evictions < GLYPH_ATLAS_THRASH_EVICTIONS_PER_FRAME {
+            self.thrash_streak_frames = 0;
+            return;
+        }
+        self.thrash_streak_frames = self.thrash_streak_frames.saturating_add(1);
+        let throttled = self.last_thrash_warning_frame.is_some_and(|frame| {
+            current_frame.saturating_sub(frame) < GLYPH_ATLAS_THRASH_WARNING_INTERVAL_FRAMES
+        });
+        if self.thrash_streak_frames >= GLYPH_ATLAS_THRASH_STREAK_FRAMES && !throttled {
+            self.last_thrash_warning_frame = Some(current_frame);
+            warn!(
+                target: "vertexlauncher/textui",
+                "atlas thrashing: {evictions} evictions/frame over {} pages of {}px; consider a larger atlas page size or growth limit",
+                self.pages.len(),
+                self.page_side_px
+            );
+        }
+    }
+
+    pub(super) fn set_growth_policy(&mut self, growth_policy: TextAtlasGrowthPolicy) {
+        self.growth_policy = growth_policy;
     }
 
     pub(super) fn set_padding(&mut self, padding_px: usize) {
@@ -502,6 +778,9 @@ impl GlyphAtlas {
     }
 
     pub(super) fn set_rasterization(&mut self, rasterization: TextRasterizationConfig) {
+        if self.rasterization != rasterization {
+            self.below_min_coverage.clear();
+        }
         self.rasterization = rasterization;
     }
 
@@ -518,6 +797,7 @@ impl GlyphAtlas {
         self.pending.clear();
         self.ready.clear();
         let _ = self.entries.write(|state| state.clear());
+        self.below_min_coverage.clear();
         self.free_all_pages();
     }
 
@@ -532,9 +812,24 @@ impl GlyphAtlas {
         self.pending.clear();
         self.ready.clear();
         let _ = self.entries.write(|state| state.clear());
+        self.below_min_coverage.clear();
         self.free_all_pages();
     }
 
+    /// Drops every cached glyph and resets page allocators but keeps the page textures, so the
+    /// next uploads overwrite stale texels instead of reallocating (and flashing) textures.
+    pub(super) fn soft_clear(&mut self) {
+        self.generation = self.generation.saturating_add(1);
+        self.pending.clear();
+        self.ready.clear();
+        let _ = self.entries.write(|state| state.clear());
+        self.below_min_coverage.clear();
+        for page in &mut self.pages {
+            page.allocator = page_allocator(&page.backing);
+            page.live_glyphs = 0;
+        }
+    }
+
     pub(super) fn generation(&self) -> u64 {
         self.generation
     }
@@ -580,6 +875,7 @@ impl GlyphAtlas {
             }
         }
 
+        self.restore_lost_egui_textures(ctx);
         self.flush_dirty_pages();
 
         if worker_disconnected {
@@ -590,6 +886,8 @@ impl GlyphAtlas {
         }
     }
 
+    /// Drops glyphs unused for `GLYPH_ATLAS_STALE_FRAMES`. Must only run at frame start (from
+    /// `TextUi::begin_frame_info`), before any quads referencing the atlas are emitted.
     pub(super) fn trim_stale(&mut self, current_frame: u64) {
         let stale_before = current_frame.saturating_sub(GLYPH_ATLAS_STALE_FRAMES);
         let evicted = self
@@ -613,6 +911,41 @@ impl GlyphAtlas {
         }
     }
 
+    /// Reports how a resident glyph was rasterized, without promoting it in the LRU.
+    pub(super) fn glyph_content(&self, cache_key: &GlyphRasterKey) -> Option<TextGlyphContent> {
+        self.entries
+            .read(|state| Some(state.get(cache_key)?.value.content()))
+    }
+
+    /// Every resident glyph's page rectangle, ordered by page, then top-to-bottom and
+    /// left-to-right, so packing snapshots compare stably across runs.
+    pub(super) fn sorted_allocations(&self) -> Vec<TextAtlasAllocation> {
+        let mut allocations: Vec<_> = self
+            .entries
+            .read(|state| state.values_cloned())
+            .into_iter()
+            .map(|entry| TextAtlasAllocation {
+                page_index: entry.page_index,
+                min_px: entry.atlas_min_px,
+                size_px: entry.size_px,
+                content: entry.content(),
+            })
+            .collect();
+        allocations.sort_by_key(|allocation| {
+            (
+                allocation.page_index,
+                allocation.min_px[1],
+                allocation.min_px[0],
+            )
+        });
+        allocations
+    }
+
+    /// Reports whether `cache_key` is resident without promoting it in the LRU.
+    pub(super) fn contains(&self, cache_key: &GlyphRasterKey) -> bool {
+        self.entries.read(|state| state.contains_key(cache_key))
+    }
+
     pub(super) fn resolve_or_queue(
         &mut self,
         ctx: &Context,
@@ -628,6 +961,9 @@ impl GlyphAtlas {
         }) {
             return Some(self.resolve_entry(&entry));
         }
+        if self.below_min_coverage.contains(&cache_key) {
+            return None;
+        }
 
         if !self.pending.contains(&cache_key) {
             let queued = self.tx.as_ref().is_some_and(|tx| {
@@ -671,6 +1007,9 @@ impl GlyphAtlas {
         }) {
             return Some(self.resolve_entry(&entry));
         }
+        if self.below_min_coverage.contains(&cache_key) {
+            return None;
+        }
 
         let glyph = rasterize_atlas_glyph(
             font_system,
@@ -690,6 +1029,12 @@ impl GlyphAtlas {
         current_frame: u64,
         flush_immediately: bool,
     ) -> Option<ResolvedGlyphAtlasEntry> {
+        if glyph.is_below_min_coverage() {
+            self.below_min_coverage.insert(cache_key);
+            return None;
+        }
+        // Recorded before the page-size check so glyphs too large for a page still show up.
+        self.record_glyph_size(glyph.size_px);
         let allocation_size = size2(
             glyph.upload_image.size[0] as i32,
             glyph.upload_image.size[1] as i32,
@@ -707,7 +1052,7 @@ impl GlyphAtlas {
             if self.try_add_page(ctx, glyph.content_mode) {
                 continue;
             }
-            if !self.evict_one_lru() {
+            if !self.evict_one_lru(current_frame) {
                 return None;
             }
         };
@@ -725,6 +1070,7 @@ impl GlyphAtlas {
             placement_left_px: glyph.placement_left_px,
             placement_top_px: glyph.placement_top_px,
             is_color: glyph.is_color,
+            subpixel_mask: glyph.subpixel_mask,
             content_mode: glyph.content_mode,
             last_used_frame: current_frame,
             approx_bytes: glyph.approx_bytes,
@@ -734,7 +1080,7 @@ impl GlyphAtlas {
         self.entries.write(|state| {
             state.insert_without_eviction(cache_key, entry, approx_bytes);
         });
-        if flush_immediately {
+        if flush_immediately && !self.coalesce_uploads {
             self.flush_page_upload(page_index);
         }
         Some(resolved)
@@ -764,11 +1110,30 @@ impl GlyphAtlas {
         // The GPU texture is kept as-is; stale pixels at unreachable UVs are harmless.
         for page in &mut self.pages {
             if page.live_glyphs == 0 && page.content_mode == content_mode {
-                page.allocator = AtlasAllocator::new(size2(side_i, side_i));
+                page.allocator = page_allocator(&page.backing);
                 return true;
             }
         }
 
+        let may_grow = match self.growth_policy {
+            TextAtlasGrowthPolicy::Grow => true,
+            TextAtlasGrowthPolicy::GrowUpTo(max_pages) => self.pages.len() < max_pages.max(1),
+            TextAtlasGrowthPolicy::EvictOnly => self
+                .pages
+                .iter()
+                .all(|page| page.content_mode != content_mode),
+        };
+        if !may_grow {
+            // At the cap, a page emptied by eviction may switch content kinds so glyphs of a
+            // kind without pages of its own can still be placed.
+            let Some(page) = self.pages.iter_mut().find(|page| page.live_glyphs == 0) else {
+                return false;
+            };
+            page.allocator = page_allocator(&page.backing);
+            page.content_mode = content_mode;
+            return true;
+        }
+
         // No reusable page; allocate a fresh GPU texture.
         let texture = self.allocate_page_texture(ctx, side);
         self.pages.push(GlyphAtlasPage {
@@ -779,6 +1144,7 @@ impl GlyphAtlas {
             cached_page_data: Mutex::new(None),
             dirty_rect: None,
             live_glyphs: 0,
+            revision: 0,
         });
         true
     }
@@ -818,10 +1184,23 @@ impl GlyphAtlas {
         }
     }
 
-    fn evict_one_lru(&mut self) -> bool {
-        let removed = self.entries.write(|state| state.pop_lru());
+    /// Frees the least recently used glyph that was not touched during `current_frame`.
+    ///
+    /// Glyphs resolved earlier in the frame may already back emitted quads that have not been
+    /// rendered yet, so they are never reclaimed mid-frame; the caller drops the new glyph
+    /// instead and it is retried next frame.
+    fn evict_one_lru(&mut self, current_frame: u64) -> bool {
+        let protected_since = if self.protect_previous_frame {
+            current_frame.saturating_sub(1)
+        } else {
+            current_frame
+        };
+        let removed = self.entries.write(|state| {
+            state.pop_lru_where(|_, entry| entry.value.last_used_frame < protected_since)
+        });
         if let Some((_, entry)) = removed {
             self.deallocate_entry(entry);
+            self.frame_evictions += 1;
             true
         } else {
             false
@@ -894,6 +1273,28 @@ impl GlyphAtlas {
         Some(data)
     }
 
+    pub(super) fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    pub(super) fn page_pixels(&self, page_index: usize) -> Option<(&[Color32], [usize; 2], u64)> {
+        let page = self.pages.get(page_index)?;
+        Some((&page.backing.pixels, page.backing.size, page.revision))
+    }
+
+    pub(super) fn largest_glyph_px(&self) -> [usize; 2] {
+        self.largest_glyph_px
+    }
+
+    /// Grows [`Self::largest_glyph_px`]; also fed by the GPU scene glyph cache, which
+    /// rasterizes label glyphs without going through the atlas.
+    pub(super) fn record_glyph_size(&mut self, size_px: [usize; 2]) {
+        self.largest_glyph_px = [
+            self.largest_glyph_px[0].max(size_px[0]),
+            self.largest_glyph_px[1].max(size_px[1]),
+        ];
+    }
+
     pub(super) fn texture_id_for_page(&self, page_index: usize) -> Option<TextureId> {
         self.pages.get(page_index).map(|page| page.texture.id())
     }
@@ -928,6 +1329,28 @@ impl GlyphAtlas {
                 .map_or(dirty, |existing| existing.union(dirty)),
         );
         page.live_glyphs = page.live_glyphs.saturating_add(1);
+        page.revision = page.revision.wrapping_add(1);
+    }
+
+    /// Re-creates egui-managed page textures the context no longer knows about (e.g. after a
+    /// context reset) from the CPU backing, so cached glyphs keep resolving to live texels
+    /// instead of partial uploads landing in a freed texture.
+    fn restore_lost_egui_textures(&mut self, ctx: &Context) {
+        let options = texture_options_for_sampling(self.sampling);
+        for (page_index, page) in self.pages.iter_mut().enumerate() {
+            let GlyphAtlasTexture::Egui(texture) = &page.texture else {
+                continue;
+            };
+            if ctx.tex_manager().read().meta(texture.id()).is_some() {
+                continue;
+            }
+            page.texture = GlyphAtlasTexture::Egui(ctx.load_texture(
+                format!("textui_glyph_atlas_{page_index}"),
+                page.backing.clone(),
+                options,
+            ));
+            page.dirty_rect = None;
+        }
     }
 
     fn flush_dirty_pages(&mut self) {
@@ -943,9 +1366,9 @@ impl GlyphAtlas {
         let Some(dirty_rect) = page.dirty_rect.take() else {
             return;
         };
-        let image = color_image_sub_image(&page.backing, dirty_rect);
         match &mut page.texture {
             GlyphAtlasTexture::Egui(texture) => {
+                let image = color_image_sub_image(&page.backing, dirty_rect);
                 texture.set_partial(
                     dirty_rect.min,
                     egui::ImageData::Color(image.into()),
@@ -954,11 +1377,12 @@ impl GlyphAtlas {
             }
             GlyphAtlasTexture::Wgpu(texture) => {
                 if let Some(render_state) = self.wgpu_render_state.as_ref() {
-                    write_color_image_to_wgpu_texture(
+                    write_color_image_region_to_wgpu_texture(
                         &render_state.queue,
                         &texture.texture,
-                        dirty_rect.min,
-                        &image,
+                        &page.backing,
+                        dirty_rect,
+                        &mut self.upload_scratch,
                     );
                 }
             }
@@ -974,6 +1398,12 @@ impl Drop for GlyphAtlas {
     }
 }
 
+/// Allocator spanning a page's own backing, which may predate a page-side change; repacking
+/// at the current target side instead would hand out rects outside the texture.
+fn page_allocator(backing: &ColorImage) -> AtlasAllocator {
+    AtlasAllocator::new(size2(backing.size[0] as i32, backing.size[1] as i32))
+}
+
 fn glyph_atlas_worker_loop(
     rx: mpsc::Receiver<GlyphAtlasWorkerMessage>,
     tx: mpsc::Sender<GlyphAtlasWorkerResponse>,
@@ -1041,7 +1471,10 @@ pub(super) fn rasterize_atlas_glyph(
         return None;
     }
 
-    let glyph_image = swash_image_to_color_image(&image)?;
+    if below_min_mask_coverage(&image, &rasterization) {
+        return Some(PreparedAtlasGlyph::below_min_coverage());
+    }
+    let glyph_image = swash_image_to_color_image(&image, &rasterization)?;
     let upload_image = build_atlas_upload_image(&glyph_image, padding_px);
     Some(PreparedAtlasGlyph {
         approx_bytes: color_image_byte_size(&upload_image),
@@ -1050,6 +1483,7 @@ pub(super) fn rasterize_atlas_glyph(
         placement_left_px: image.placement.left,
         placement_top_px: image.placement.top,
         is_color: matches!(image.content, SwashContent::Color),
+        subpixel_mask: matches!(image.content, SwashContent::SubpixelMask),
         content_mode: GlyphContentMode::AlphaMask,
     })
 }
@@ -1110,7 +1544,10 @@ fn render_swash_image(
     let mut scaler = scale_context
         .builder(swash_font)
         .size(ppem)
-        .hint(resolved_hinting_enabled(display_scale, rasterization));
+        .hint(resolved_hinting_enabled(
+            display_scale,
+            raster_key.hinting_or(rasterization.hinting),
+        ));
     if !settings.is_empty() {
         scaler = scaler.variations(settings.into_iter());
     }
@@ -1216,7 +1653,10 @@ pub(super) fn render_swash_outline_commands(
     let mut scaler = scale_context
         .builder(swash_font)
         .size(ppem)
-        .hint(resolved_hinting_enabled(display_scale, rasterization));
+        .hint(resolved_hinting_enabled(
+            display_scale,
+            raster_key.hinting_or(rasterization.hinting),
+        ));
     if !settings.is_empty() {
         scaler = scaler.variations(settings.into_iter());
     }
@@ -1365,6 +1805,7 @@ fn rasterize_field_glyph(
         placement_left_px: left,
         placement_top_px: top,
         is_color: false,
+        subpixel_mask: false,
         content_mode: cache_key.content_mode(),
     })
 }
@@ -1383,7 +1824,9 @@ fn field_glyph_matches_reference(
     else {
         return false;
     };
-    let Some(reference_image) = swash_image_to_color_image(&reference) else {
+    let Some(reference_image) =
+        swash_image_to_color_image(&reference, &TextRasterizationConfig::default())
+    else {
         return false;
     };
 
@@ -1714,7 +2157,26 @@ fn distance_to_segment(point: [f32; 2], a: [f32; 2], b: [f32; 2]) -> f32 {
     point_distance(point, closest)
 }
 
-fn swash_image_to_color_image(image: &cosmic_text::SwashImage) -> Option<ColorImage> {
+/// Whether a mask glyph's peak coverage is under `min_mask_coverage`, i.e. too faint to be
+/// worth an atlas slot.
+fn below_min_mask_coverage(
+    image: &cosmic_text::SwashImage,
+    rasterization: &TextRasterizationConfig,
+) -> bool {
+    let Some(min_coverage) = rasterization.min_mask_coverage else {
+        return false;
+    };
+    if !matches!(image.content, SwashContent::Mask) {
+        return false;
+    }
+    let peak = image.data.iter().copied().max().unwrap_or(0);
+    (peak as f32) < min_coverage.clamp(0.0, 1.0) * 255.0
+}
+
+fn swash_image_to_color_image(
+    image: &cosmic_text::SwashImage,
+    rasterization: &TextRasterizationConfig,
+) -> Option<ColorImage> {
     let width = image.placement.width as usize;
     let height = image.placement.height as usize;
     if width == 0 || height == 0 {
@@ -1722,21 +2184,69 @@ fn swash_image_to_color_image(image: &cosmic_text::SwashImage) -> Option<ColorIm
     }
 
     let pixels = match image.content {
-        SwashContent::Mask => image
-            .data
-            .iter()
-            .map(|alpha| Color32::from_white_alpha(*alpha))
-            .collect::<Vec<_>>(),
+        SwashContent::Mask => {
+            let coverage_curve = rasterization
+                .dark_mode_contrast
+                .filter(|exponent| exponent.is_finite() && *exponent > 0.0)
+                .map(|exponent| {
+                    std::array::from_fn::<u8, 256, _>(|alpha| {
+                        ((alpha as f32 / 255.0).powf(exponent) * 255.0).round() as u8
+                    })
+                });
+            image
+                .data
+                .iter()
+                .map(|alpha| {
+                    let alpha = coverage_curve.map_or(*alpha, |curve| curve[*alpha as usize]);
+                    Color32::from_white_alpha(alpha)
+                })
+                .collect::<Vec<_>>()
+        }
         SwashContent::Color | SwashContent::SubpixelMask => image
             .data
             .chunks_exact(4)
-            .map(|rgba| Color32::from_rgba_unmultiplied(rgba[0], rgba[1], rgba[2], rgba[3]))
+            .map(|rgba| {
+                let is_color = matches!(image.content, SwashContent::Color);
+                let [r, g, b] = match rasterization.color_glyph_filter {
+                    Some(filter) if is_color => {
+                        filter_color_glyph_rgb(filter, [rgba[0], rgba[1], rgba[2]])
+                    }
+                    _ => [rgba[0], rgba[1], rgba[2]],
+                };
+                if is_color && rasterization.color_alpha_mode == TextColorAlphaMode::Premultiplied {
+                    Color32::from_rgba_premultiplied(r, g, b, rgba[3])
+                } else {
+                    Color32::from_rgba_unmultiplied(r, g, b, rgba[3])
+                }
+            })
             .collect::<Vec<_>>(),
     };
 
     Some(ColorImage::new([width, height], pixels))
 }
 
+fn filter_color_glyph_rgb(filter: TextColorGlyphFilter, rgb: [u8; 3]) -> [u8; 3] {
+    let [r, g, b] = rgb.map(f32::from);
+    let luma = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+    let filtered = match filter {
+        TextColorGlyphFilter::Grayscale => [luma; 3],
+        TextColorGlyphFilter::Sepia => [
+            0.393 * r + 0.769 * g + 0.189 * b,
+            0.349 * r + 0.686 * g + 0.168 * b,
+            0.272 * r + 0.534 * g + 0.131 * b,
+        ],
+        TextColorGlyphFilter::Tint(color) => {
+            let luma = luma / 255.0;
+            [
+                f32::from(color.r()) * luma,
+                f32::from(color.g()) * luma,
+                f32::from(color.b()) * luma,
+            ]
+        }
+    };
+    filtered.map(|channel| channel.round().clamp(0.0, 255.0) as u8)
+}
+
 fn build_atlas_upload_image(glyph: &ColorImage, padding: usize) -> ColorImage {
     let mut upload = ColorImage::filled(
         [
@@ -1787,19 +2297,24 @@ fn color_image_sub_image(src: &ColorImage, rect: DirtyAtlasRect) -> ColorImage {
     image
 }
 
-fn write_color_image_to_wgpu_texture(
+/// Uploads `rect` of `src` into `texture`, encoding through the caller-owned `scratch` buffer
+/// so repeated flushes reuse one allocation instead of building a sub-image per upload.
+fn write_color_image_region_to_wgpu_texture(
     queue: &wgpu::Queue,
     texture: &wgpu::Texture,
-    pos: [usize; 2],
-    image: &ColorImage,
+    src: &ColorImage,
+    rect: DirtyAtlasRect,
+    scratch: &mut Vec<u8>,
 ) {
-    if image.size[0] == 0 || image.size[1] == 0 {
+    let region_size = rect.size();
+    if region_size[0] == 0 || region_size[1] == 0 {
         return;
     }
-    let bytes = color_image_to_rgba16f_bytes(image);
+    color_image_region_to_rgba16f_bytes(src, rect, scratch);
+    let pos = rect.min;
     let size = wgpu::Extent3d {
-        width: image.size[0] as u32,
-        height: image.size[1] as u32,
+        width: region_size[0] as u32,
+        height: region_size[1] as u32,
         depth_or_array_layers: 1,
     };
     queue.write_texture(
@@ -1813,23 +2328,32 @@ fn write_color_image_to_wgpu_texture(
             },
             aspect: wgpu::TextureAspect::All,
         },
-        &bytes,
+        scratch,
         wgpu::TexelCopyBufferLayout {
             offset: 0,
-            bytes_per_row: Some(8 * image.size[0] as u32),
-            rows_per_image: Some(image.size[1] as u32),
+            bytes_per_row: Some(8 * region_size[0] as u32),
+            rows_per_image: Some(region_size[1] as u32),
         },
         size,
     );
 }
 
-fn color_image_to_rgba16f_bytes(image: &ColorImage) -> Vec<u8> {
-    let mut bytes = Vec::with_capacity(image.pixels.len().saturating_mul(8));
-    for pixel in &image.pixels {
-        for channel in pixel.to_array() {
-            let half = half::f16::from_f32(f32::from(channel) / 255.0);
-            bytes.extend_from_slice(&half.to_bits().to_le_bytes());
+fn color_image_region_to_rgba16f_bytes(
+    src: &ColorImage,
+    rect: DirtyAtlasRect,
+    bytes: &mut Vec<u8>,
+) {
+    let size = rect.size();
+    bytes.clear();
+    bytes.reserve(size[0].saturating_mul(size[1]).saturating_mul(8));
+    let src_width = src.size[0];
+    for y in rect.min[1]..rect.max[1] {
+        let row_start = y * src_width + rect.min[0];
+        for pixel in &src.pixels[row_start..row_start + size[0]] {
+            for channel in pixel.to_array() {
+                let half = half::f16::from_f32(f32::from(channel) / 255.0);
+                bytes.extend_from_slice(&half.to_bits().to_le_bytes());
+            }
         }
     }
-    bytes
 }
@@ -78,3 +78,36 @@ pub(crate) fn editor_sel_rect(
         Vec2::new((max_x - min_x).max(0) as f32 / scale, line_height / scale),
     )
 }
+
+pub(crate) fn editor_caret_rect(
+    x_px: f32,
+    line_top: f32,
+    line_height: f32,
+    horiz_scroll_px: f32,
+    origin: Pos2,
+    scale: f32,
+) -> Rect {
+    Rect::from_min_size(
+        Pos2::new(
+            (x_px - horiz_scroll_px) / scale + origin.x,
+            line_top / scale + origin.y,
+        ),
+        Vec2::new((1.0_f32 / scale).max(0.5), line_height / scale),
+    )
+}
+
+/// Top and height of the caret within a line box, in buffer pixels.
+pub(crate) fn caret_vertical_span(
+    caret_height: TextCaretHeight,
+    line_top: f32,
+    line_height: f32,
+    font_size: f32,
+) -> (f32, f32) {
+    match caret_height {
+        TextCaretHeight::LineHeight => (line_top, line_height),
+        TextCaretHeight::TextExtent => {
+            let height = font_size.min(line_height);
+            (line_top + (line_height - height) * 0.5, height)
+        }
+    }
+}
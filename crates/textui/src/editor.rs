@@ -865,6 +865,14 @@ fn motion_uses_preferred_cursor_x(motion: Motion) -> bool {
     )
 }
 
+/// Applies (or clears) justified alignment on every line before the buffer is shaped.
+pub(super) fn set_buffer_justification(buffer: &mut Buffer, justify: bool) {
+    let align = justify.then_some(Align::Justified);
+    for line in &mut buffer.lines {
+        line.set_align(align);
+    }
+}
+
 pub(super) fn measure_buffer_pixels(buffer: &Buffer) -> (usize, usize) {
     let mut max_right = 0.0_f32;
     let mut max_bottom = 0.0_f32;
@@ -951,6 +959,7 @@ pub(super) fn clamp_borrowed_buffer_scroll(
     buffer: &mut BorrowedWithFontSystem<'_, Buffer>,
     fundamentals: &TextFundamentals,
     scale: f32,
+    prune_shaped_lines: bool,
 ) -> EditorScrollMetrics {
     let mut scroll_metrics = measure_borrowed_buffer_scroll_metrics(buffer, fundamentals, scale);
     let mut scroll = buffer.scroll();
@@ -960,7 +969,7 @@ pub(super) fn clamp_borrowed_buffer_scroll(
     if (clamped_horizontal - scroll.horizontal).abs() > f32::EPSILON {
         scroll.horizontal = clamped_horizontal;
         buffer.set_scroll(scroll);
-        buffer.shape_until_scroll(true);
+        buffer.shape_until_scroll(prune_shaped_lines);
     }
     scroll_metrics.current_horizontal_scroll_px = clamped_horizontal;
     scroll_metrics
@@ -54,6 +54,9 @@ pub(crate) fn compose_font_features(
     settings.insert(*b"case", u16::from(fundamentals.case_sensitive_forms));
     settings.insert(*b"zero", u16::from(fundamentals.slashed_zero));
     settings.insert(*b"tnum", u16::from(fundamentals.tabular_numbers));
+    if fundamentals.vertical {
+        settings.insert(*b"vert", 1);
+    }
     for feature in &fundamentals.feature_settings {
         settings.insert(feature.tag, feature.value);
     }
@@ -85,11 +88,8 @@ fn should_hint(display_scale: f32) -> bool {
 }
 
 #[inline]
-pub(crate) fn resolved_hinting_enabled(
-    display_scale: f32,
-    rasterization: TextRasterizationConfig,
-) -> bool {
-    match rasterization.hinting {
+pub(crate) fn resolved_hinting_enabled(display_scale: f32, hinting: TextHintingMode) -> bool {
+    match hinting {
         TextHintingMode::Enabled => true,
         TextHintingMode::Disabled => false,
         TextHintingMode::Auto => should_hint(display_scale),
@@ -143,6 +143,51 @@ fn choose_available_family<'a>(db: &fontdb::Database, families: &'a [&'a str]) -
         .find(|family| font_family_available(db, family))
 }
 
+/// Loads bundled font bytes and points every generic family whose configured face is missing
+/// at the first loaded family, so text renders even without system fonts. Returns the loaded
+/// family names.
+pub(crate) fn load_fallback_font_data(font_system: &mut FontSystem, bytes: Vec<u8>) -> Vec<String> {
+    let db = font_system.db_mut();
+    let ids = db.load_font_source(fontdb::Source::Binary(Arc::new(bytes)));
+    let mut families = Vec::new();
+    for id in ids {
+        if let Some((family, _)) = db.face(id).and_then(|face| face.families.first())
+            && !families.contains(family)
+        {
+            families.push(family.clone());
+        }
+    }
+    let Some(fallback) = families.first().cloned() else {
+        return families;
+    };
+    if !font_family_available(db, db.family_name(&fontdb::Family::SansSerif)) {
+        db.set_sans_serif_family(fallback.clone());
+    }
+    if !font_family_available(db, db.family_name(&fontdb::Family::Serif)) {
+        db.set_serif_family(fallback.clone());
+    }
+    if !font_family_available(db, db.family_name(&fontdb::Family::Monospace)) {
+        db.set_monospace_family(fallback);
+    }
+    families
+}
+
+/// Builds a font system over the system fonts with the platform family defaults applied.
+/// `locale` (a BCP 47 tag such as `ja-JP`) replaces the OS locale cosmic-text consults when
+/// picking fallback faces, which decides the regional forms of unified Han characters.
+pub(crate) fn new_text_font_system(locale: Option<&str>) -> FontSystem {
+    let mut font_system = match locale {
+        Some(locale) => {
+            let mut db = fontdb::Database::new();
+            db.load_system_fonts();
+            FontSystem::new_with_locale_and_db(locale.to_owned(), db)
+        }
+        None => FontSystem::new(),
+    };
+    configure_text_font_defaults(&mut font_system);
+    font_system
+}
+
 pub(crate) fn configure_text_font_defaults(font_system: &mut FontSystem) {
     let db = font_system.db_mut();
 
@@ -24,6 +24,41 @@ pub(crate) fn snap_rect_to_pixel_grid(rect: Rect, pixels_per_point: f32) -> Rect
     )
 }
 
+/// Scales a color glyph's box by `scale` about the point on the baseline under its centre,
+/// leaving mask glyphs and the advance untouched. `origin_offset` is the box's top-left
+/// relative to the glyph's baseline origin.
+#[inline]
+pub(crate) fn scale_color_glyph_box(
+    is_color: bool,
+    origin_offset: Vec2,
+    size: Vec2,
+    scale: f32,
+) -> (Vec2, Vec2) {
+    if !is_color || !scale.is_finite() || scale <= 0.0 || scale == 1.0 {
+        return (origin_offset, size);
+    }
+    (
+        egui::vec2(
+            origin_offset.x + size.x * (1.0 - scale) * 0.5,
+            origin_offset.y * scale,
+        ),
+        size * scale,
+    )
+}
+
+/// Alpha factor for a glyph near the clip boundary: `1.0` while `glyph_rect` stays inside
+/// `clip_rect`, falling linearly to `0.0` once it extends `fade_points` past any edge.
+pub(crate) fn clip_fade_factor(glyph_rect: Rect, clip_rect: Rect, fade_points: f32) -> f32 {
+    if !fade_points.is_finite() || fade_points <= 0.0 {
+        return 1.0;
+    }
+    let overhang = (clip_rect.min.x - glyph_rect.min.x)
+        .max(glyph_rect.max.x - clip_rect.max.x)
+        .max(clip_rect.min.y - glyph_rect.min.y)
+        .max(glyph_rect.max.y - clip_rect.max.y);
+    (1.0 - overhang / fade_points).clamp(0.0, 1.0)
+}
+
 pub(crate) fn egui_vec_from_text(vector: TextVector) -> Vec2 {
     vector.into()
 }
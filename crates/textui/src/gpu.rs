@@ -402,7 +402,7 @@ pub(super) fn color_image_to_page_data(page_index: usize, image: &ColorImage) ->
     }
 }
 
-pub(super) fn quad_positions_from_min_size(min: Pos2, size: Vec2) -> [Pos2; 4] {
+pub(crate) fn quad_positions_from_min_size(min: Pos2, size: Vec2) -> [Pos2; 4] {
     [
         min,
         Pos2::new(min.x + size.x, min.y),
@@ -427,7 +427,7 @@ pub(super) fn rotated_quad_positions(
     .map(|offset| anchor + rotation * offset)
 }
 
-pub(super) fn uv_quad_points(uv: Rect) -> [Pos2; 4] {
+pub(crate) fn uv_quad_points(uv: Rect) -> [Pos2; 4] {
     [
         uv.min,
         Pos2::new(uv.max.x, uv.min.y),
@@ -436,7 +436,7 @@ pub(super) fn uv_quad_points(uv: Rect) -> [Pos2; 4] {
     ]
 }
 
-pub(super) fn rect_from_points(points: [Pos2; 4]) -> Rect {
+pub(crate) fn rect_from_points(points: [Pos2; 4]) -> Rect {
     let mut min = points[0];
     let mut max = points[0];
     for point in &points[1..] {
@@ -1,5 +1,5 @@
 use super::*;
-use std::collections::HashMap;
+use rustc_hash::FxHashMap;
 
 pub(crate) struct TextWgpuCachedTextureBinding {
     pub(crate) bind_group: wgpu::BindGroup,
@@ -7,7 +7,7 @@ pub(crate) struct TextWgpuCachedTextureBinding {
 
 #[derive(Default)]
 pub(crate) struct TextWgpuTextureBindingCache {
-    pub(crate) entries: HashMap<(u64, usize), TextWgpuCachedTextureBinding>,
+    pub(crate) entries: FxHashMap<(u64, usize), TextWgpuCachedTextureBinding>,
 }
 
 #[derive(Default)]
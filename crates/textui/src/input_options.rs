@@ -1,4 +1,4 @@
-use crate::TextFundamentals;
+use crate::{TextCaretHeight, TextFundamentals};
 use egui::{Color32, Stroke, Vec2};
 
 /// Styling/behavior options for single/multi-line text inputs.
@@ -24,6 +24,20 @@ pub struct InputOptions {
     pub desired_rows: usize,
     pub placeholder_text: Option<String>,
     pub placeholder_color: Option<Color32>,
+    /// Width in points over which glyphs fade out as they scroll past the clip edge. `0.0`
+    /// keeps the hard clip.
+    pub clip_fade_points: f32,
+    /// Debug overlay: draws a hairline at every visible line's baseline in this color.
+    pub baseline_debug_color: Option<Color32>,
+    /// Debug overlay: strokes the painted rect of every glyph quad in this color.
+    pub glyph_box_debug_color: Option<Color32>,
+    /// Draws this stroke under the text at every visible line's baseline, e.g. the focus
+    /// stroke for underline-style fields.
+    pub baseline_stroke: Option<Stroke>,
+    /// Snaps each glyph's origin (not its size) to the device-pixel grid so the text lines up
+    /// with surrounding egui widgets. Off by default, which keeps subpixel glyph positions.
+    pub align_to_egui_grid: bool,
+    pub caret_height: TextCaretHeight,
     pub fundamentals: TextFundamentals,
 }
 
@@ -50,6 +64,12 @@ impl Default for InputOptions {
             desired_rows: 5,
             placeholder_text: None,
             placeholder_color: None,
+            clip_fade_points: 0.0,
+            baseline_debug_color: None,
+            glyph_box_debug_color: None,
+            baseline_stroke: None,
+            align_to_egui_grid: false,
+            caret_height: TextCaretHeight::LineHeight,
             fundamentals: TextFundamentals::default(),
         }
     }
@@ -10,10 +10,10 @@ const TEXT_WGPU_INSTANCED_SHADER: &str = include_str!("shaders/text_instanced.wg
 
 use bytemuck::{Pod, Zeroable};
 use cosmic_text::{
-    Action, Affinity, Attrs, AttrsOwned, BorrowedWithFontSystem, Buffer, CacheKey, Color, Cursor,
-    Edit, Editor, Family, FontFeatures, FontSystem, LayoutGlyph, LayoutRun, Metrics, Motion,
-    Selection, Shaping, Style as FontStyle, SubpixelBin, SwashContent, SwashImage, Weight, Wrap,
-    fontdb,
+    Action, Affinity, Align, Attrs, AttrsOwned, BorrowedWithFontSystem, Buffer, CacheKey, Color,
+    Cursor, Edit, Editor, Family, FontFeatures, FontSystem, LayoutGlyph, LayoutRun, Metrics,
+    Motion, Selection, Shaping, Style as FontStyle, SubpixelBin, SwashContent, SwashImage, Weight,
+    Wrap, fontdb,
 };
 use egui::{
     self, Color32, ColorImage, Context, CornerRadius, Id, Key, Pos2, Rect, Response, Sense,
@@ -76,17 +76,20 @@ pub use self::text_ui::TextUi;
 use crate::async_raster::{AsyncRasterState, AsyncRasterWorkerMessage, new_async_raster_state};
 pub(crate) use crate::atlas::{
     GlyphAtlas, GlyphContentMode, GlyphRasterKey, PaintTextQuad, PreparedAtlasGlyph,
-    adjusted_glyph_right_px, adjusted_glyph_x_px, collect_glyph_spacing_prefixes_px,
-    collect_prepared_glyphs_from_buffer, cursor_stops_for_glyphs, glyph_logical_font_size_points,
-    hash_text_fundamentals, hit_buffer_with_fundamentals, rasterize_atlas_glyph,
-    render_swash_outline_commands, shared_variation_settings,
+    ResolvedGlyphAtlasEntry, adjusted_glyph_right_px, adjusted_glyph_x_px,
+    collect_glyph_spacing_prefixes_px, collect_prepared_glyphs_from_buffer,
+    collect_vertical_prepared_glyphs_from_buffer, cursor_stops_for_glyphs,
+    glyph_logical_font_size_points, hash_text_fundamentals, hit_buffer_with_fundamentals,
+    rasterize_atlas_glyph, render_swash_outline_commands, shared_variation_settings,
 };
 pub(crate) use crate::conversions::{
     core_label_options, cosmic_to_egui_color, egui_key_from_text, egui_modifiers_from_text,
     glyph_content_mode_from_rasterization, multiply_color32, texture_options_for_sampling,
     to_cosmic_color, to_cosmic_text_color, wgpu_filter_mode_for_sampling,
 };
-use crate::cursor_layout::{editor_cursor_x_in_run, editor_sel_rect};
+use crate::cursor_layout::{
+    caret_vertical_span, editor_caret_rect, editor_cursor_x_in_run, editor_sel_rect,
+};
 use crate::editor::{
     EditorScrollMetrics, InputState, UndoEntry, UndoOpKind, clamp_borrowed_buffer_scroll,
     clamp_cursor_to_editor, clamp_selection_to_editor, click_editor_to_pointer,
@@ -94,15 +97,17 @@ use crate::editor::{
     editor_to_string, extend_selection_to_pointer, handle_editor_key_event,
     handle_read_only_editor_key_event, is_navigation_event, measure_borrowed_buffer_scroll_metrics,
     measure_buffer_pixels, pending_modify_op, push_undo, scroll_editor_to_buffer_end, select_all,
-    triple_click_editor_to_pointer, viewer_scrollbar_track_rects, viewer_visible_text_rect,
+    set_buffer_justification, triple_click_editor_to_pointer, viewer_scrollbar_track_rects,
+    viewer_visible_text_rect,
 };
 pub(crate) use crate::font_features::{
-    build_font_features, compose_font_features, configure_text_font_defaults, opsz_for_font_size,
-    parse_feature_tag_list, resolved_hinting_enabled, resolved_stem_darkening_strength,
+    build_font_features, compose_font_features, configure_text_font_defaults,
+    load_fallback_font_data, new_text_font_system, opsz_for_font_size, parse_feature_tag_list,
+    resolved_hinting_enabled, resolved_stem_darkening_strength,
 };
 use crate::geometry::{
-    egui_point_from_text, egui_rect_from_text, egui_vec_from_text, snap_rect_to_pixel_grid,
-    snap_width_to_bin,
+    clip_fade_factor, egui_point_from_text, egui_rect_from_text, egui_vec_from_text,
+    scale_color_glyph_box, snap_rect_to_pixel_grid, snap_width_to_bin,
 };
 use crate::gpu::{
     CpuSceneAtlasPage, ResolvedTextGraphicsConfig, ResolvedTextRendererBackend, TextWgpuInstance,
@@ -114,7 +119,7 @@ use crate::gpu::{
 };
 use crate::input_runtime::apply_gamepad_scroll_if_focused;
 use crate::path_layout::{
-    build_path_layout_from_prepared_layout, export_prepared_layout_as_shapes,
+    build_path_layout_from_prepared_layout, export_prepared_layout_as_shapes, outline_bounds,
 };
 pub(crate) use crate::prepared_layout::{
     PreparedGlyph, PreparedTextCacheEntry, PreparedTextLayout,
@@ -127,13 +132,15 @@ use crate::{
 
 pub use advanced_text::DEFAULT_ELLIPSIS;
 pub use advanced_text::{
-    RichTextSpan, RichTextStyle, TextAtlasPageData, TextAtlasPageSnapshot, TextAtlasQuad,
-    TextAtlasSampling, TextColor, TextFeatureSetting, TextFrameInfo, TextFrameOutput,
-    TextFundamentals, TextGlyphRasterMode, TextGpuPowerPreference, TextGpuQuad, TextGpuScene,
-    TextGpuSceneDrawOptions, TextGpuScenePageBatch, TextGraphicsApi, TextGraphicsConfig,
-    TextHintingMode, TextInputEvent, TextKerning, TextKey, TextLabelOptions, TextMarkdownBlock,
-    TextMarkdownHeadingLevel, TextModifiers, TextOpticalSizingMode, TextPath, TextPathError,
-    TextPathGlyph, TextPathLayout, TextPathOptions, TextPoint, TextPointerButton,
+    RichTextSpan, RichTextStyle, TextAtlasAllocation, TextAtlasCheckerOptions,
+    TextAtlasGrowthPolicy, TextAtlasPageData, TextAtlasPageSnapshot, TextAtlasQuad,
+    TextAtlasResidency, TextAtlasSampling, TextCaretHeight, TextColor, TextColorAlphaMode,
+    TextColorGlyphFilter, TextFeatureSetting, TextFrameInfo, TextFrameOutput, TextFundamentals,
+    TextGlyphContent, TextGlyphDrawInfo, TextGlyphRasterMode, TextGpuPowerPreference, TextGpuQuad,
+    TextGpuScene, TextGpuSceneDrawOptions, TextGpuScenePageBatch, TextGraphicsApi,
+    TextGraphicsConfig, TextHintingMode, TextInputEvent, TextKerning, TextKey, TextLabelOptions,
+    TextMarkdownBlock, TextMarkdownHeadingLevel, TextModifiers, TextOpticalSizingMode, TextPath,
+    TextPathError, TextPathGlyph, TextPathLayout, TextPathOptions, TextPoint, TextPointerButton,
     TextRasterizationConfig, TextRect, TextRenderScene, TextRendererBackend, TextRenderingPolicy,
     TextStemDarkeningMode, TextVariationSetting, TextVector, VectorGlyphShape, VectorPathCommand,
     VectorTextShape,
@@ -148,12 +155,17 @@ pub use input_options::InputOptions as EguiInputOptions;
 /// Default OpenType feature tags applied when no explicit feature string is
 /// provided to [`TextUi::apply_open_type_features`].
 pub const DEFAULT_OPEN_TYPE_FEATURE_TAGS: &str = "kern, liga, calt, onum, pnum";
+/// Color given to fallback-font glyphs while [`TextUi::set_strict_fonts`] is enabled.
+pub const STRICT_FONT_FALLBACK_COLOR: Color32 = Color32::from_rgb(255, 0, 255);
 const PREPARED_TEXT_CACHE_MAX_BYTES: usize = 16 * 1024 * 1024;
 const ASYNC_RASTER_CACHE_MAX_BYTES: usize = 24 * 1024 * 1024;
 const GPU_SCENE_CACHE_MAX_BYTES: usize = 32 * 1024 * 1024;
 const GPU_SCENE_PAGE_BATCH_CACHE_MAX_BYTES: usize = 24 * 1024 * 1024;
 const GPU_SCENE_DRAW_BATCH_CACHE_MAX_BYTES: usize = 32 * 1024 * 1024;
 const GPU_SCENE_GLYPH_CACHE_MAX_BYTES: usize = 24 * 1024 * 1024;
+const GLYPH_INK_BOUNDS_CACHE_MAX_BYTES: usize = 2 * 1024 * 1024;
+const MIN_PLAUSIBLE_MAX_TEXTURE_SIDE_PX: usize = 256;
+const FALLBACK_MAX_TEXTURE_SIDE_PX: usize = 2048;
 const GLYPH_ATLAS_MAX_BYTES: usize = 64 * 1024 * 1024;
 const GLYPH_ATLAS_STALE_FRAMES: u64 = 900;
 const GLYPH_ATLAS_PAGE_TARGET_PX: usize = 1024;
@@ -161,6 +173,9 @@ const GLYPH_ATLAS_PADDING_PX: i32 = 1;
 const GLYPH_ATLAS_FETCH_MAX_PER_FRAME: usize = 128;
 const GLYPH_ATLAS_UPLOAD_MAX_GLYPHS_PER_FRAME: usize = 64;
 const GLYPH_ATLAS_UPLOAD_MAX_BYTES_PER_FRAME: usize = 512 * 1024;
+const GLYPH_ATLAS_THRASH_EVICTIONS_PER_FRAME: usize = 32;
+const GLYPH_ATLAS_THRASH_STREAK_FRAMES: u32 = 30;
+const GLYPH_ATLAS_THRASH_WARNING_INTERVAL_FRAMES: u64 = 600;
 const AUTO_MSDF_MIN_LOGICAL_FONT_SIZE_PT: f32 = 28.0;
 const FIELD_GLYPH_MEAN_ALPHA_ERROR_LIMIT: f32 = 0.045;
 const FIELD_GLYPH_MAX_ALPHA_ERROR_LIMIT: f32 = 0.25;
@@ -203,15 +218,28 @@ impl TextUi {
     pub fn begin_frame_info(&mut self, frame_info: TextFrameInfo) {
         self.current_frame = frame_info.frame_number;
         let current_frame = self.current_frame;
-        let max_texture_side_px = frame_info.max_texture_side_px.max(1);
+        // Some backends report 0 until they finish initializing; clamping pages to that would
+        // wedge the atlas, so implausible sides fall back to a safe default until a real one
+        // arrives.
+        let max_texture_side_px =
+            if frame_info.max_texture_side_px < MIN_PLAUSIBLE_MAX_TEXTURE_SIDE_PX {
+                FALLBACK_MAX_TEXTURE_SIDE_PX
+            } else {
+                frame_info.max_texture_side_px
+            };
         let graphics_config = self.resolved_graphics_config(max_texture_side_px);
         self.frame_events.clear();
+        if let Some(log) = self.glyph_draw_log.as_mut() {
+            log.clear();
+        }
         self.glyph_atlas
             .set_page_side(graphics_config.atlas_page_target_px);
         self.glyph_atlas
             .set_sampling(graphics_config.atlas_sampling);
         self.glyph_atlas
             .set_padding(graphics_config.atlas_padding_px);
+        self.glyph_atlas
+            .set_growth_policy(self.graphics_config.atlas_growth_policy);
         self.glyph_atlas
             .set_rasterization(graphics_config.rasterization);
         self.glyph_atlas
@@ -232,6 +260,7 @@ impl TextUi {
         self.input_states.retain(|_, state| {
             current_frame.saturating_sub(state.last_used_frame) <= INPUT_STATE_STALE_FRAMES
         });
+        self.glyph_atlas.check_thrashing(current_frame);
         self.glyph_atlas.trim_stale(current_frame);
         self.enforce_prepared_text_cache_budget();
         self.enforce_async_raster_cache_budget();
@@ -269,6 +298,20 @@ impl TextUi {
         TextFrameOutput { needs_repaint }
     }
 
+    /// Batches the atlas uploads of glyphs rasterized during the frame into one region per
+    /// page instead of one upload per glyph. While enabled, call `textui_egui::end_frame`
+    /// after the last text of the frame is painted (or `textui_egui::flush_atlas_uploads`
+    /// before a mid-frame readback).
+    pub fn set_atlas_upload_coalescing(&mut self, enabled: bool) {
+        self.glyph_atlas.set_coalesce_uploads(enabled);
+    }
+
+    #[doc(hidden)]
+    /// Uploads atlas regions deferred by [`Self::set_atlas_upload_coalescing`].
+    pub fn egui_flush_atlas_uploads(&mut self) {
+        self.glyph_atlas.flush_uploads();
+    }
+
     pub fn set_graphics_config(&mut self, graphics_config: TextGraphicsConfig) {
         if self.graphics_config != graphics_config {
             self.graphics_config = graphics_config;
@@ -280,6 +323,36 @@ impl TextUi {
         self.graphics_config
     }
 
+    /// Changes atlas filtering without invalidating any caches, e.g. nearest while a view is
+    /// being dragged and linear once it settles.
+    pub fn set_atlas_sampling(&mut self, sampling: TextAtlasSampling) {
+        self.graphics_config.atlas_sampling = sampling;
+        self.glyph_atlas.set_sampling(sampling);
+    }
+
+    pub fn atlas_sampling(&self) -> TextAtlasSampling {
+        self.graphics_config.atlas_sampling
+    }
+
+    /// Changes when the atlas may add pages. Existing pages are kept even if they exceed a
+    /// new [`TextAtlasGrowthPolicy::GrowUpTo`] limit; the limit applies to future growth.
+    pub fn set_atlas_growth_policy(&mut self, growth_policy: TextAtlasGrowthPolicy) {
+        self.graphics_config.atlas_growth_policy = growth_policy;
+        self.glyph_atlas.set_growth_policy(growth_policy);
+    }
+
+    /// Requires a glyph to go unused for two consecutive frames before the atlas may evict it
+    /// to make room, so quads retained from the previous frame never sample a reused slot.
+    pub fn set_atlas_protect_previous_frame(&mut self, enabled: bool) {
+        self.glyph_atlas.set_protect_previous_frame(enabled);
+    }
+
+    /// Logs a throttled warning while the atlas keeps evicting glyphs to make room frame after
+    /// frame, a sign that its pages are too small (or too few) for the text on screen.
+    pub fn set_atlas_thrash_warnings(&mut self, enabled: bool) {
+        self.glyph_atlas.set_thrash_warnings(enabled);
+    }
+
     pub fn set_gpu_instancing_enabled(&mut self, enabled: bool) {
         let mut graphics_config = self.graphics_config;
         graphics_config.renderer_backend = if enabled {
@@ -298,6 +371,20 @@ impl TextUi {
         )
     }
 
+    /// Controls whether editor and viewer buffers drop shaped lines outside the
+    /// visible scroll range when they are reshaped.
+    ///
+    /// Pruning is on by default. Disabling it keeps already-shaped lines cached
+    /// across frames so scrolling back and forth through long documents does not
+    /// reshape them, at the cost of holding more layout data in memory.
+    pub fn set_prune_shaped_lines(&mut self, prune: bool) {
+        self.prune_shaped_lines = prune;
+    }
+
+    pub fn prune_shaped_lines(&self) -> bool {
+        self.prune_shaped_lines
+    }
+
     fn resolved_graphics_config(&self, max_texture_side_px: usize) -> ResolvedTextGraphicsConfig {
         let renderer_backend = match self.graphics_config.renderer_backend {
             TextRendererBackend::Auto => ResolvedTextRendererBackend::WgpuInstanced,
@@ -343,6 +430,64 @@ impl TextUi {
         }
     }
 
+    /// Reports whether any font face is loaded. Without one, shaping yields no glyphs and all
+    /// text paints blank, e.g. in minimal containers; register a bundled font in that case.
+    pub fn has_fonts(&self) -> bool {
+        !self.font_system.db().is_empty()
+    }
+
+    /// Strict font mode, for reproducible screenshot tests: glyphs whose face is not one of
+    /// the configured families (the UI family or the sans/serif/monospace generics) are painted
+    /// in [`STRICT_FONT_FALLBACK_COLOR`]. Applies to layouts shaped on the UI thread; use
+    /// [`Self::fallback_glyph_count`] to reject them.
+    pub fn set_strict_fonts(&mut self, strict_fonts: bool) {
+        if self.strict_fonts == strict_fonts {
+            return;
+        }
+        self.strict_fonts = strict_fonts;
+        self.invalidate_text_caches(false);
+    }
+
+    pub fn strict_fonts(&self) -> bool {
+        self.strict_fonts
+    }
+
+    /// Counts the glyphs of `text` shaped from a face outside the configured families, i.e.
+    /// picked by cosmic-text's fallback. Works whether or not strict mode is enabled.
+    pub fn fallback_glyph_count(
+        &mut self,
+        text: &str,
+        options: &TextLabelOptions,
+        width_points_opt: Option<f32>,
+    ) -> usize {
+        let options = core_label_options(options);
+        let layout = self.prepare_plain_text_layout(text, &options, width_points_opt, 1.0);
+        layout
+            .glyphs
+            .iter()
+            .filter(|glyph| !self.is_configured_font(glyph.cache_key.font_id()))
+            .count()
+    }
+
+    pub(crate) fn is_configured_font(&self, font_id: fontdb::ID) -> bool {
+        let db = self.font_system.db();
+        let Some(face) = db.face(font_id) else {
+            return false;
+        };
+        let configured = [
+            self.ui_font_family.as_deref(),
+            Some(db.family_name(&fontdb::Family::SansSerif)),
+            Some(db.family_name(&fontdb::Family::Serif)),
+            Some(db.family_name(&fontdb::Family::Monospace)),
+        ];
+        face.families.iter().any(|(family, _)| {
+            configured
+                .iter()
+                .flatten()
+                .any(|name| family.eq_ignore_ascii_case(name))
+        })
+    }
+
     /// Registers additional font bytes for rendering.
     ///
     /// This clears cached textures/input states so new faces are picked up.
@@ -355,6 +500,22 @@ impl TextUi {
         self.invalidate_text_caches(true);
     }
 
+    /// Registers bundled font bytes and makes them the face behind any generic family (sans,
+    /// serif, monospace) the system does not provide, guaranteeing text renders in sandboxed
+    /// environments. Returns the registered family names for use with
+    /// [`Self::apply_typography`].
+    pub fn register_fallback_font_data(&mut self, bytes: Vec<u8>) -> Vec<String> {
+        if let Some(tx) = self.async_raster.tx.as_ref() {
+            let _ = tx.send(AsyncRasterWorkerMessage::RegisterFallbackFont(
+                bytes.clone(),
+            ));
+        }
+        self.glyph_atlas.register_font(bytes.clone());
+        let families = load_fallback_font_data(&mut self.font_system, bytes);
+        self.invalidate_text_caches(true);
+        families
+    }
+
     /// Renders an asynchronously rasterized label.
     #[allow(dead_code)]
     fn label_async(
@@ -636,14 +797,61 @@ impl TextUi {
         blocks
     }
 
+    /// Forgets all atlas glyphs without releasing page textures. Cheaper than a full clear when
+    /// everything is about to be repainted; scenes built before the call must be rebuilt.
+    pub fn soft_clear_glyph_atlas(&mut self) {
+        self.glyph_atlas.soft_clear();
+    }
+
     pub fn atlas_page_snapshot(&self, page_index: usize) -> Option<TextAtlasPageSnapshot> {
         self.glyph_atlas.page_snapshot(page_index)
     }
 
+    pub fn atlas_page_count(&self) -> usize {
+        self.glyph_atlas.page_count()
+    }
+
+    /// Borrows the authoritative CPU copy of an atlas page (premultiplied, row-major) with its
+    /// size and a revision that changes whenever glyphs are written to it. Software or
+    /// out-of-process compositors can poll the revision and copy only changed pages.
+    pub fn atlas_page_pixels(&self, page_index: usize) -> Option<(&[Color32], [usize; 2], u64)> {
+        self.glyph_atlas.page_pixels(page_index)
+    }
+
+    /// Resident glyph rectangles sorted by page, row and column, for golden-file comparisons
+    /// of atlas packing.
+    pub fn atlas_sorted_allocations(&self) -> Vec<TextAtlasAllocation> {
+        self.glyph_atlas.sorted_allocations()
+    }
+
     pub fn atlas_page_data(&self, page_index: usize) -> Option<TextAtlasPageData> {
         self.glyph_atlas.page_data(page_index)
     }
 
+    /// Records every glyph placed by scene builds and editor painting, with whether the cache it
+    /// came from (the glyph atlas, or the GPU scene glyph cache for labels) already held it, so
+    /// overlays can highlight atlas thrash. The log is cleared at the start of each frame.
+    pub fn set_glyph_draw_logging(&mut self, enabled: bool) {
+        if enabled != self.glyph_draw_log.is_some() {
+            self.glyph_draw_log = enabled.then(Vec::new);
+        }
+    }
+
+    /// Returns the glyphs logged since the frame began, leaving the log empty.
+    pub fn take_glyph_draws(&mut self) -> Vec<TextGlyphDrawInfo> {
+        self.glyph_draw_log
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
+    /// Largest rasterized glyph, in pixels, offered to the atlas or rasterized for GPU scene
+    /// labels so far (including glyphs too large to fit a page). Useful for sizing atlas pages
+    /// against real content.
+    pub fn atlas_largest_glyph_px(&self) -> [usize; 2] {
+        self.glyph_atlas.largest_glyph_px()
+    }
+
     pub fn atlas_page_snapshots_for_scene(
         &self,
         scene: &TextRenderScene,
@@ -768,6 +976,9 @@ impl TextUi {
         let transformed_batches = source_batches
             .iter()
             .map(|batch| {
+                // Quads of one run share a source tint, so the multiply is redone only when the
+                // color changes.
+                let mut last_tint: Option<([u8; 4], [u8; 4])> = None;
                 let quads = batch
                     .quads
                     .iter()
@@ -778,20 +989,28 @@ impl TextUi {
                                 options.offset.y + point[1] * options.scale.y,
                             ]
                         });
-                        let tint = multiply_color32(
-                            Color32::from_rgba_premultiplied(
-                                quad.tint_rgba[0],
-                                quad.tint_rgba[1],
-                                quad.tint_rgba[2],
-                                quad.tint_rgba[3],
-                            ),
-                            options.tint.into(),
-                        );
+                        let tint_rgba = match last_tint {
+                            Some((source, tinted)) if source == quad.tint_rgba => tinted,
+                            _ => {
+                                let tinted = multiply_color32(
+                                    Color32::from_rgba_premultiplied(
+                                        quad.tint_rgba[0],
+                                        quad.tint_rgba[1],
+                                        quad.tint_rgba[2],
+                                        quad.tint_rgba[3],
+                                    ),
+                                    options.tint.into(),
+                                )
+                                .to_array();
+                                last_tint = Some((quad.tint_rgba, tinted));
+                                tinted
+                            }
+                        };
                         TextGpuQuad {
                             atlas_page_index: quad.atlas_page_index,
                             positions,
                             uvs: quad.uvs,
-                            tint_rgba: tint.to_array(),
+                            tint_rgba,
                         }
                     })
                     .collect::<Vec<_>>();
@@ -1377,7 +1596,25 @@ impl TextUi {
             return;
         }
 
-        if let Some(callback) = self.build_text_wgpu_scene_callback(quads) {
+        // egui maps a paint callback's rect through the layer transform but the instanced quads
+        // inside are drawn in global screen points, so quads on transformed layers (scaled or
+        // panned areas) are mapped here. The mesh fallback is transformed by egui itself.
+        let layer_transform = painter
+            .ctx()
+            .layer_transform_to_global(painter.layer_id())
+            .filter(|transform| *transform != egui::emath::TSTransform::IDENTITY);
+        let global_quads = layer_transform.map(|transform| {
+            quads
+                .iter()
+                .map(|quad| PaintTextQuad {
+                    positions: quad.positions.map(|point| transform * point),
+                    ..quad.clone()
+                })
+                .collect::<Vec<_>>()
+        });
+        let callback_quads = global_quads.as_deref().unwrap_or(quads);
+
+        if let Some(callback) = self.build_text_wgpu_scene_callback(callback_quads) {
             let callback_rect = bounds.intersect(painter.clip_rect());
             if callback_rect.is_positive() {
                 painter.add(egui_wgpu::Callback::new_paint_callback(
@@ -1401,6 +1638,7 @@ impl TextUi {
         let _ = self.gpu_scene_page_batch_cache.write(|state| state.clear());
         let _ = self.gpu_scene_draw_batch_cache.write(|state| state.clear());
         let _ = self.gpu_scene_glyph_cache.write(|state| state.clear());
+        let _ = self.glyph_ink_bounds_cache.write(|state| state.clear());
         if clear_input_states {
             self.input_states.clear();
         }
@@ -1410,6 +1648,9 @@ impl TextUi {
         self.prepared_texts.write(|state| {
             let _ = state.evict_to_budget();
         });
+        self.glyph_ink_bounds_cache.write(|state| {
+            let _ = state.evict_to_budget();
+        });
     }
 
     fn enforce_gpu_scene_cache_budget(&mut self) {
@@ -1435,7 +1676,14 @@ impl TextUi {
     }
 
     fn effective_line_height(&self, line_height_points: f32) -> f32 {
-        (line_height_points * self.ui_font_size_scale).max(1.0)
+        // `f32::max` already maps NaN and non-positive metrics to the floor, but an infinite
+        // line height would still propagate into run geometry, so treat it the same way.
+        let line_height = line_height_points * self.ui_font_size_scale;
+        if line_height.is_finite() {
+            line_height.max(1.0)
+        } else {
+            1.0
+        }
     }
 
     fn effective_weight(&self, base_weight: u16) -> u16 {
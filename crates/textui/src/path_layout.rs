@@ -202,6 +202,19 @@ fn export_vector_glyph_shape(
     })
 }
 
+/// Bounds of an outline's points relative to the glyph origin, in y-down space. Control points
+/// are included, so curves may overestimate slightly.
+pub(super) fn outline_bounds(commands: &[swash::zeno::Command]) -> Option<Rect> {
+    let mut bounds: Option<Rect> = None;
+    for command in commands {
+        update_vector_shape_bounds(
+            &mut bounds,
+            &map_outline_command_to_points(*command, Vec2::ZERO),
+        );
+    }
+    bounds
+}
+
 fn map_outline_command_to_points(
     command: swash::zeno::Command,
     glyph_origin: Vec2,
@@ -20,13 +20,21 @@ pub struct TextUi {
     pub(crate) current_frame: u64,
     pub(crate) max_texture_side_px: usize,
     pub(crate) frame_events: Vec<TextInputEvent>,
+    /// Passed to cosmic-text when reshaping editor/viewer buffers on scroll.
+    pub(crate) prune_shaped_lines: bool,
     pub(crate) markdown_cache: FxHashMap<Id, (u64, u64, Arc<[TextMarkdownBlock]>)>,
     pub(crate) gpu_scene_cache: ThreadSafeLru<u64, Arc<TextGpuScene>>,
     pub(crate) gpu_scene_page_batch_cache: ThreadSafeLru<u64, Arc<[TextGpuScenePageBatch]>>,
     pub(crate) gpu_scene_draw_batch_cache: ThreadSafeLru<u64, Arc<[TextGpuScenePageBatch]>>,
     pub(crate) gpu_scene_glyph_cache: ThreadSafeLru<GlyphRasterKey, Arc<PreparedAtlasGlyph>>,
+    /// Outline bounds relative to the glyph origin, for measurement without rasterizing.
+    pub(crate) glyph_ink_bounds_cache: ThreadSafeLru<GlyphRasterKey, Option<Rect>>,
     /// Reusable CPU-side atlas pages to avoid per-frame alloc/free of large pixel buffers.
     pub(crate) cpu_page_pool: Vec<CpuSceneAtlasPage>,
+    /// Glyphs emitted by atlas scene builds this frame; `None` while logging is disabled.
+    pub(crate) glyph_draw_log: Option<Vec<TextGlyphDrawInfo>>,
+    /// Recolors glyphs shaped from a fallback face so screenshots expose them.
+    pub(crate) strict_fonts: bool,
 }
 
 impl Default for TextUi {
@@ -41,6 +49,19 @@ impl TextUi {
     }
 
     pub fn new_with_graphics_config(graphics_config: TextGraphicsConfig) -> Self {
+        Self::new_with_graphics_config_and_locale(graphics_config, None)
+    }
+
+    /// Like [`Self::new_with_graphics_config`] but shapes with `locale` (e.g. `"ja-JP"`)
+    /// instead of the OS locale, so fallback picks the matching regional CJK faces.
+    pub fn new_with_locale(graphics_config: TextGraphicsConfig, locale: &str) -> Self {
+        Self::new_with_graphics_config_and_locale(graphics_config, Some(locale))
+    }
+
+    fn new_with_graphics_config_and_locale(
+        graphics_config: TextGraphicsConfig,
+        locale: Option<&str>,
+    ) -> Self {
         let syntax_set = SyntaxSet::load_defaults_newlines();
         let theme_set = ThemeSet::load_defaults();
         let code_theme = theme_set
@@ -57,8 +78,13 @@ impl TextUi {
             });
 
         let glyph_atlas = GlyphAtlas::new();
-        let mut font_system = FontSystem::new();
-        configure_text_font_defaults(&mut font_system);
+        let font_system = new_text_font_system(locale);
+        if font_system.db().is_empty() {
+            warn!(
+                target: "vertexlauncher/textui",
+                "no system fonts were found; text will not render until fonts are registered"
+            );
+        }
         Self {
             font_system,
             scale_context: ScaleContext::new(),
@@ -74,17 +100,21 @@ impl TextUi {
             open_type_features_to_enable: String::new(),
             open_type_feature_tags: Vec::new(),
             open_type_features: None,
-            async_raster: new_async_raster_state(),
+            async_raster: new_async_raster_state(locale.map(str::to_owned)),
             graphics_config,
             current_frame: 0,
             max_texture_side_px: usize::MAX,
             frame_events: Vec::new(),
+            prune_shaped_lines: true,
             markdown_cache: FxHashMap::default(),
             gpu_scene_cache: ThreadSafeLru::new(GPU_SCENE_CACHE_MAX_BYTES),
             gpu_scene_page_batch_cache: ThreadSafeLru::new(GPU_SCENE_PAGE_BATCH_CACHE_MAX_BYTES),
             gpu_scene_draw_batch_cache: ThreadSafeLru::new(GPU_SCENE_DRAW_BATCH_CACHE_MAX_BYTES),
             gpu_scene_glyph_cache: ThreadSafeLru::new(GPU_SCENE_GLYPH_CACHE_MAX_BYTES),
+            glyph_ink_bounds_cache: ThreadSafeLru::new(GLYPH_INK_BOUNDS_CACHE_MAX_BYTES),
             cpu_page_pool: Vec::new(),
+            glyph_draw_log: None,
+            strict_fonts: false,
         }
     }
 }
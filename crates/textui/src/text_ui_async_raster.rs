@@ -23,11 +23,11 @@ pub(super) use self::async_raster_state::AsyncRasterState;
 pub(super) use self::async_raster_worker_message::AsyncRasterWorkerMessage;
 pub(super) use self::typography_snapshot::TypographySnapshot;
 
-pub(super) fn new_async_raster_state() -> AsyncRasterState {
+pub(super) fn new_async_raster_state(locale: Option<String>) -> AsyncRasterState {
     let (worker_tx, worker_rx) = mpsc::channel::<AsyncRasterWorkerMessage>();
     let (result_tx, result_rx) = mpsc::channel::<AsyncRasterResponse>();
     let _ = tokio_runtime::spawn_blocking_detached(move || {
-        async_raster_worker_loop(worker_rx, result_tx)
+        async_raster_worker_loop(worker_rx, result_tx, locale)
     });
     AsyncRasterState {
         tx: Some(worker_tx),
@@ -193,15 +193,18 @@ impl TextUi {
 fn async_raster_worker_loop(
     rx: mpsc::Receiver<AsyncRasterWorkerMessage>,
     tx: mpsc::Sender<AsyncRasterResponse>,
+    locale: Option<String>,
 ) {
-    let mut font_system = FontSystem::new();
-    configure_text_font_defaults(&mut font_system);
+    let mut font_system = new_text_font_system(locale.as_deref());
 
     while let Ok(msg) = rx.recv() {
         match msg {
             AsyncRasterWorkerMessage::RegisterFont(bytes) => {
                 font_system.db_mut().load_font_data(bytes);
             }
+            AsyncRasterWorkerMessage::RegisterFallbackFont(bytes) => {
+                let _ = load_fallback_font_data(&mut font_system, bytes);
+            }
             AsyncRasterWorkerMessage::Render(req) => {
                 let layout = async_prepare_text_layout(&mut font_system, &req);
                 let _ = tx.send(AsyncRasterResponse {
@@ -269,6 +272,7 @@ fn async_prepare_text_layout(
                 borrowed.set_rich_text(rich_text, &default_attrs, Shaping::Advanced, None);
             }
         }
+        set_buffer_justification(&mut borrowed, req.options.fundamentals.justify);
         borrowed.shape_until_scroll(true);
     }
 
@@ -278,6 +282,19 @@ fn async_prepare_text_layout(
     }
     let width_px = measured_width_px.max(1);
     let height_px = measured_height_px.max(1);
+    if req.options.fundamentals.vertical {
+        let (glyphs, size_points) = collect_vertical_prepared_glyphs_from_buffer(
+            &buffer,
+            req.scale,
+            req.options.color,
+            &req.options.fundamentals,
+        );
+        return PreparedTextLayout {
+            approx_bytes: glyphs.len().saturating_mul(mem::size_of::<PreparedGlyph>()),
+            glyphs: Arc::from(glyphs),
+            size_points,
+        };
+    }
     let (glyphs, extra_width_points) = collect_prepared_glyphs_from_buffer(
         &buffer,
         req.scale,
@@ -2,5 +2,6 @@ use super::*;
 
 pub(crate) enum AsyncRasterWorkerMessage {
     RegisterFont(Vec<u8>),
+    RegisterFallbackFont(Vec<u8>),
     Render(AsyncRasterRequest),
 }
@@ -697,11 +697,13 @@ impl TextUi {
     ) -> EditorScrollMetrics {
         let attrs_owned = self.input_attrs_owned(options, scale);
         let effective_font_size = self.effective_font_size(options.font_size) * scale;
-        let effective_line_height = self.effective_line_height(options.line_height) * scale;
+        let effective_line_height =
+            (self.effective_line_height(options.line_height) * scale).max(1.0);
         let previous_cursor = editor.cursor();
         let previous_selection = editor.selection();
         let previous_scroll = editor.with_buffer(|buffer| buffer.scroll());
         let mut scroll_metrics = EditorScrollMetrics::default();
+        let prune_shaped_lines = self.prune_shaped_lines;
         editor.with_buffer_mut(|buffer| {
             let mut borrowed = buffer.borrow_with(&mut self.font_system);
             borrowed.set_metrics_and_size(
@@ -717,9 +719,13 @@ impl TextUi {
             let attrs = attrs_owned.as_attrs();
             borrowed.set_text(text, &attrs, Shaping::Advanced, None);
             borrowed.set_scroll(previous_scroll);
-            borrowed.shape_until_scroll(true);
-            scroll_metrics =
-                clamp_borrowed_buffer_scroll(&mut borrowed, &options.fundamentals, scale);
+            borrowed.shape_until_scroll(prune_shaped_lines);
+            scroll_metrics = clamp_borrowed_buffer_scroll(
+                &mut borrowed,
+                &options.fundamentals,
+                scale,
+                prune_shaped_lines,
+            );
         });
         editor.set_cursor(clamp_cursor_to_editor(editor, previous_cursor));
         editor.set_selection(clamp_selection_to_editor(editor, previous_selection));
@@ -736,8 +742,10 @@ impl TextUi {
         scale: f32,
     ) -> EditorScrollMetrics {
         let effective_font_size = self.effective_font_size(options.font_size) * scale;
-        let effective_line_height = self.effective_line_height(options.line_height) * scale;
+        let effective_line_height =
+            (self.effective_line_height(options.line_height) * scale).max(1.0);
         let mut scroll_metrics = EditorScrollMetrics::default();
+        let prune_shaped_lines = self.prune_shaped_lines;
         editor.with_buffer_mut(|buffer| {
             let mut borrowed = buffer.borrow_with(&mut self.font_system);
             borrowed.set_metrics_and_size(
@@ -750,9 +758,13 @@ impl TextUi {
             } else {
                 Wrap::None
             });
-            borrowed.shape_until_scroll(true);
-            scroll_metrics =
-                clamp_borrowed_buffer_scroll(&mut borrowed, &options.fundamentals, scale);
+            borrowed.shape_until_scroll(prune_shaped_lines);
+            scroll_metrics = clamp_borrowed_buffer_scroll(
+                &mut borrowed,
+                &options.fundamentals,
+                scale,
+                prune_shaped_lines,
+            );
         });
         scroll_metrics
     }
@@ -768,7 +780,8 @@ impl TextUi {
         wrap: bool,
     ) -> EditorScrollMetrics {
         let effective_font_size = self.effective_font_size(options.font_size) * scale;
-        let effective_line_height = self.effective_line_height(options.line_height) * scale;
+        let effective_line_height =
+            (self.effective_line_height(options.line_height) * scale).max(1.0);
         let previous_cursor = editor.cursor();
         let previous_selection = editor.selection();
         let previous_scroll = editor.with_buffer(|buffer| buffer.scroll());
@@ -778,6 +791,7 @@ impl TextUi {
             .map(|span| self.input_span_attrs_owned(&span.style, options, scale))
             .collect::<Vec<_>>();
         let mut scroll_metrics = EditorScrollMetrics::default();
+        let prune_shaped_lines = self.prune_shaped_lines;
 
         editor.with_buffer_mut(|buffer| {
             let mut borrowed = buffer.borrow_with(&mut self.font_system);
@@ -799,9 +813,13 @@ impl TextUi {
                 None,
             );
             borrowed.set_scroll(previous_scroll);
-            borrowed.shape_until_scroll(true);
-            scroll_metrics =
-                clamp_borrowed_buffer_scroll(&mut borrowed, &options.fundamentals, scale);
+            borrowed.shape_until_scroll(prune_shaped_lines);
+            scroll_metrics = clamp_borrowed_buffer_scroll(
+                &mut borrowed,
+                &options.fundamentals,
+                scale,
+                prune_shaped_lines,
+            );
         });
         editor.set_cursor(clamp_cursor_to_editor(editor, previous_cursor));
         editor.set_selection(clamp_selection_to_editor(editor, previous_selection));
@@ -818,8 +836,10 @@ impl TextUi {
         wrap: bool,
     ) -> EditorScrollMetrics {
         let effective_font_size = self.effective_font_size(options.font_size) * scale;
-        let effective_line_height = self.effective_line_height(options.line_height) * scale;
+        let effective_line_height =
+            (self.effective_line_height(options.line_height) * scale).max(1.0);
         let mut scroll_metrics = EditorScrollMetrics::default();
+        let prune_shaped_lines = self.prune_shaped_lines;
         editor.with_buffer_mut(|buffer| {
             let mut borrowed = buffer.borrow_with(&mut self.font_system);
             borrowed.set_metrics_and_size(
@@ -828,9 +848,13 @@ impl TextUi {
                 Some(height_px),
             );
             borrowed.set_wrap(if wrap { Wrap::WordOrGlyph } else { Wrap::None });
-            borrowed.shape_until_scroll(true);
-            scroll_metrics =
-                clamp_borrowed_buffer_scroll(&mut borrowed, &options.fundamentals, scale);
+            borrowed.shape_until_scroll(prune_shaped_lines);
+            scroll_metrics = clamp_borrowed_buffer_scroll(
+                &mut borrowed,
+                &options.fundamentals,
+                scale,
+                prune_shaped_lines,
+            );
         });
         scroll_metrics
     }
@@ -1011,12 +1035,13 @@ impl TextUi {
         delta_px: f32,
         max_horizontal_scroll_px: f32,
     ) {
+        let prune_shaped_lines = self.prune_shaped_lines;
         editor.with_buffer_mut(|buffer| {
             let mut borrowed = buffer.borrow_with(&mut self.font_system);
             let mut scroll = borrowed.scroll();
             scroll.horizontal = (scroll.horizontal + delta_px).clamp(0.0, max_horizontal_scroll_px);
             borrowed.set_scroll(scroll);
-            borrowed.shape_until_scroll(true);
+            borrowed.shape_until_scroll(prune_shaped_lines);
         });
     }
 
@@ -1025,12 +1050,13 @@ impl TextUi {
         editor: &mut Editor<'static>,
         delta_px: f32,
     ) {
+        let prune_shaped_lines = self.prune_shaped_lines;
         editor.with_buffer_mut(|buffer| {
             let mut borrowed = buffer.borrow_with(&mut self.font_system);
             let mut scroll = borrowed.scroll();
             scroll.vertical += delta_px;
             borrowed.set_scroll(scroll);
-            borrowed.shape_until_scroll(true);
+            borrowed.shape_until_scroll(prune_shaped_lines);
         });
     }
 
@@ -1151,16 +1177,23 @@ impl TextUi {
         let mut sel_rects: Vec<Rect> = Vec::new();
         let mut cursor_rect: Option<Rect> = None;
         let mut glyph_cmds: Vec<GlyphCmd> = Vec::new();
+        let mut baselines_px: Vec<f32> = Vec::new();
         let variation_settings = shared_variation_settings(&options.fundamentals);
 
         editor.with_buffer(|buffer| {
             let buf_width = buffer.size().0.unwrap_or(0.0);
+            let font_size_px = buffer.metrics().font_size;
+            let clip_min_x_px = (painter.clip_rect().min.x - origin.x) * scale;
+            let clip_max_x_px = (painter.clip_rect().max.x - origin.x) * scale;
 
             for run in buffer.layout_runs() {
                 let line_i = run.line_i;
                 let line_top = run.line_top;
                 let line_y = run.line_y;
                 let line_height = run.line_height;
+                if options.baseline_debug_color.is_some() || options.baseline_stroke.is_some() {
+                    baselines_px.push(line_y);
+                }
                 let prefixes = collect_glyph_spacing_prefixes_px(
                     run.text,
                     run.glyphs,
@@ -1235,16 +1268,52 @@ impl TextUi {
                     && let Some(cx) =
                         editor_cursor_x_in_run(&editor.cursor(), &run, &options.fundamentals, scale)
                 {
-                    let x_pts = (cx as f32 - horizontal_scroll_px) / scale + origin.x;
-                    let y_pts = line_top / scale + origin.y;
-                    let h_pts = line_height / scale;
-                    cursor_rect = Some(Rect::from_min_size(
-                        Pos2::new(x_pts, y_pts),
-                        Vec2::new((1.0_f32 / scale).max(0.5), h_pts),
+                    let (caret_top, caret_height) = caret_vertical_span(
+                        options.caret_height,
+                        line_top,
+                        line_height,
+                        font_size_px,
+                    );
+                    cursor_rect = Some(editor_caret_rect(
+                        cx as f32,
+                        caret_top,
+                        caret_height,
+                        horizontal_scroll_px,
+                        origin,
+                        scale,
                     ));
                 }
 
-                for (glyph_index, glyph) in run.glyphs.iter().enumerate() {
+                // Long single-line runs only have a window of glyphs on screen. Walk the run from
+                // its visual left edge (the last glyph when shaping stored it right-to-left),
+                // skip glyphs left of the clip and stop at the first one past its right edge.
+                // Mixed-direction runs are not ordered by x, so they only skip, never stop.
+                let single_direction = run
+                    .glyphs
+                    .windows(2)
+                    .all(|pair| pair[0].level.is_rtl() == pair[1].level.is_rtl());
+                let ascending = match (run.glyphs.first(), run.glyphs.last()) {
+                    (Some(first), Some(last)) => first.x <= last.x,
+                    _ => true,
+                };
+                let glyph_order: Box<dyn Iterator<Item = usize>> = if ascending {
+                    Box::new(0..run.glyphs.len())
+                } else {
+                    Box::new((0..run.glyphs.len()).rev())
+                };
+                for glyph_index in glyph_order {
+                    let glyph = &run.glyphs[glyph_index];
+                    let glyph_x_px =
+                        adjusted_glyph_x_px(glyph, prefixes[glyph_index]) - horizontal_scroll_px;
+                    if glyph_x_px > clip_max_x_px + font_size_px {
+                        if single_direction {
+                            break;
+                        }
+                        continue;
+                    }
+                    if glyph_x_px + glyph.w < clip_min_x_px - font_size_px {
+                        continue;
+                    }
                     let physical = glyph.physical((0.0, 0.0), 1.0);
                     let color = if selection_visible {
                         if let Some((start, end)) = selection_bounds {
@@ -1275,6 +1344,7 @@ impl TextUi {
                             physical.cache_key,
                             scale,
                             options.fundamentals.stem_darkening,
+                            options.fundamentals.hinting,
                             GlyphContentMode::AlphaMask,
                             0.0,
                             Arc::clone(&variation_settings),
@@ -1285,6 +1355,30 @@ impl TextUi {
                     });
                 }
             }
+
+            // Empty buffers and carets no run claims (e.g. before the first line is shaped)
+            // still get a caret at the start of the first line. `layout_runs` only yields
+            // visible runs, so a caret on a line scrolled out of view must stay hidden.
+            if has_focus
+                && cursor_rect.is_none()
+                && (editor.cursor().line == buffer.scroll().line
+                    || buffer.layout_runs().all(|run| run.glyphs.is_empty()))
+            {
+                let (caret_top, caret_height) = caret_vertical_span(
+                    options.caret_height,
+                    -buffer.scroll().vertical,
+                    buffer.metrics().line_height,
+                    font_size_px,
+                );
+                cursor_rect = Some(editor_caret_rect(
+                    0.0,
+                    caret_top,
+                    caret_height,
+                    horizontal_scroll_px,
+                    origin,
+                    scale,
+                ));
+            }
         });
 
         for sel in sel_rects {
@@ -1298,9 +1392,14 @@ impl TextUi {
         let graphics_config = self.resolved_graphics_config(self.max_texture_side_px.max(1));
         let field_range_px = graphics_config.rasterization.field_range_px.max(1.0);
         let mut quads = Vec::with_capacity(glyph_cmds.len());
+        let mut glyph_boxes: Vec<Rect> = Vec::new();
         for cmd in glyph_cmds {
             let content_mode = self.resolved_glyph_content_mode(graphics_config, &cmd.cache_key);
-            let raster_key = cmd.cache_key.for_content_mode(content_mode, field_range_px);
+            let (raster_key, raster_scale) = cmd
+                .cache_key
+                .for_content_mode(content_mode, field_range_px)
+                .quantized(graphics_config.rasterization.size_quantization_px);
+            let atlas_hit = self.glyph_draw_log.is_some() && self.glyph_atlas.contains(&raster_key);
             let Some(atlas_entry) = self.glyph_atlas.resolve_or_queue(
                 painter.ctx(),
                 &mut self.font_system,
@@ -1311,22 +1410,53 @@ impl TextUi {
                 continue;
             };
 
-            let glyph_rect = Rect::from_min_size(
-                Pos2::new(
-                    (cmd.x_px + atlas_entry.placement_left_px as f32) / scale + origin.x,
-                    (cmd.y_px - atlas_entry.placement_top_px as f32) / scale + origin.y,
+            let (origin_offset, size_points) = scale_color_glyph_box(
+                atlas_entry.is_color,
+                Vec2::new(
+                    atlas_entry.placement_left_px as f32 * raster_scale / scale,
+                    -(atlas_entry.placement_top_px as f32) * raster_scale / scale,
                 ),
                 Vec2::new(
-                    atlas_entry.size_px[0] as f32 / scale,
-                    atlas_entry.size_px[1] as f32 / scale,
+                    atlas_entry.size_px[0] as f32 * raster_scale / scale,
+                    atlas_entry.size_px[1] as f32 * raster_scale / scale,
                 ),
+                graphics_config.rasterization.color_glyph_scale,
+            );
+            let glyph_rect = Rect::from_min_size(
+                Pos2::new(cmd.x_px / scale + origin.x, cmd.y_px / scale + origin.y) + origin_offset,
+                size_points,
             );
+            // Snap the origin (not the size) to the device-pixel grid so editor text lines up
+            // with surrounding egui widgets without resampling the glyph bitmap.
+            let glyph_rect = if options.align_to_egui_grid {
+                let snapped_min =
+                    snap_rect_to_pixel_grid(Rect::from_min_size(glyph_rect.min, Vec2::ZERO), scale)
+                        .min;
+                glyph_rect.translate(snapped_min - glyph_rect.min)
+            } else {
+                glyph_rect
+            };
+            if options.glyph_box_debug_color.is_some() {
+                glyph_boxes.push(glyph_rect);
+            }
+            if let Some(log) = self.glyph_draw_log.as_mut() {
+                log.push(TextGlyphDrawInfo {
+                    rect: glyph_rect.translate(-origin.to_vec2()).into(),
+                    atlas_hit,
+                    atlas_page_index: atlas_entry.page_index,
+                });
+            }
 
             let tint = if atlas_entry.is_color {
                 Color32::WHITE
             } else {
                 cmd.color
             };
+            let tint = tint.gamma_multiply(clip_fade_factor(
+                glyph_rect,
+                painter.clip_rect(),
+                options.clip_fade_points,
+            ));
 
             quads.push(PaintTextQuad {
                 page_index: atlas_entry.page_index,
@@ -1337,8 +1467,33 @@ impl TextUi {
             });
         }
 
+        if let Some(stroke) = options.baseline_stroke {
+            for line_y in &baselines_px {
+                painter.hline(content_rect.x_range(), line_y / scale + origin.y, stroke);
+            }
+        }
+
         self.paint_text_quads(&painter, content_rect, &quads);
 
+        if let Some(color) = options.baseline_debug_color {
+            let stroke = egui::Stroke::new(1.0 / scale, color);
+            for line_y in baselines_px {
+                painter.hline(content_rect.x_range(), line_y / scale + origin.y, stroke);
+            }
+        }
+
+        if let Some(color) = options.glyph_box_debug_color {
+            let stroke = egui::Stroke::new(1.0 / scale, color);
+            for glyph_box in glyph_boxes {
+                painter.rect_stroke(
+                    glyph_box,
+                    CornerRadius::ZERO,
+                    stroke,
+                    egui::StrokeKind::Inside,
+                );
+            }
+        }
+
         if let Some(cursor_rect) = cursor_rect {
             painter.add(egui::Shape::rect_filled(
                 cursor_rect,
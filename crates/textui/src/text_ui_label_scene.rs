@@ -85,10 +85,133 @@ impl TextUi {
             borrowed.shape_until_scroll(true);
         }
 
+        if options.fundamentals.vertical {
+            // Vertical text is painted as columns, so the horizontal buffer size would
+            // allocate the wrong shape.
+            let (_, size_points) = collect_vertical_prepared_glyphs_from_buffer(
+                &buffer,
+                scale,
+                options.color,
+                &options.fundamentals,
+            );
+            return size_points.into();
+        }
         let (width_px, height_px) = measure_buffer_pixels(&buffer);
         TextVector::new(width_px as f32 / scale, height_px as f32 / scale)
     }
 
+    /// Counts how many glyphs of `text` are already resident in the glyph atlas (editor and
+    /// path text) or the GPU scene glyph cache (labels); a glyph held by either counts.
+    ///
+    /// This is a dry run: it shapes the text but never rasterizes, allocates, or
+    /// promotes cache entries, so callers can decide whether a warm-up pass is
+    /// worth running before the text is actually painted.
+    pub fn atlas_residency_at_scale(
+        &mut self,
+        scale: f32,
+        text: &str,
+        options: &TextLabelOptions,
+        width_points_opt: Option<f32>,
+    ) -> TextAtlasResidency {
+        let options = core_label_options(options);
+        let layout = self.prepare_plain_text_layout(text, &options, width_points_opt, scale);
+        let graphics_config = self.resolved_graphics_config(self.max_texture_side_px.max(1));
+        let field_range_px = graphics_config.rasterization.field_range_px.max(1.0);
+        let mut residency = TextAtlasResidency::default();
+        for glyph in layout.glyphs.iter() {
+            let content_mode = self.resolved_glyph_content_mode(graphics_config, &glyph.cache_key);
+            let (raster_key, _) = glyph
+                .cache_key
+                .for_content_mode(content_mode, field_range_px)
+                .quantized(graphics_config.rasterization.size_quantization_px);
+            let (gpu_scene_key, _) = glyph
+                .cache_key
+                .clone()
+                .quantized(graphics_config.rasterization.size_quantization_px);
+            let in_gpu_scene_cache = self.gpu_scene_glyph_cache.read(|state| {
+                state
+                    .get(&gpu_scene_key)
+                    .is_some_and(|entry| !entry.value.is_below_min_coverage())
+            });
+            if in_gpu_scene_cache || self.glyph_atlas.contains(&raster_key) {
+                residency.resident += 1;
+            } else {
+                residency.missing += 1;
+            }
+        }
+        residency
+    }
+
+    /// Dry run reporting how each laid-out glyph of `text` is stored, in layout order. The
+    /// glyph atlas (editor and path text) is checked first, then the GPU scene glyph cache
+    /// (labels); `None` marks glyphs resident in neither.
+    pub fn atlas_glyph_contents_at_scale(
+        &mut self,
+        scale: f32,
+        text: &str,
+        options: &TextLabelOptions,
+        width_points_opt: Option<f32>,
+    ) -> Vec<Option<TextGlyphContent>> {
+        let options = core_label_options(options);
+        let layout = self.prepare_plain_text_layout(text, &options, width_points_opt, scale);
+        let graphics_config = self.resolved_graphics_config(self.max_texture_side_px.max(1));
+        let field_range_px = graphics_config.rasterization.field_range_px.max(1.0);
+        layout
+            .glyphs
+            .iter()
+            .map(|glyph| {
+                let content_mode =
+                    self.resolved_glyph_content_mode(graphics_config, &glyph.cache_key);
+                let (raster_key, _) = glyph
+                    .cache_key
+                    .for_content_mode(content_mode, field_range_px)
+                    .quantized(graphics_config.rasterization.size_quantization_px);
+                self.glyph_atlas.glyph_content(&raster_key).or_else(|| {
+                    let (gpu_scene_key, _) = glyph
+                        .cache_key
+                        .clone()
+                        .quantized(graphics_config.rasterization.size_quantization_px);
+                    self.gpu_scene_glyph_cache.read(|state| {
+                        let glyph = &state.get(&gpu_scene_key)?.value;
+                        (!glyph.is_below_min_coverage()).then(|| glyph.content())
+                    })
+                })
+            })
+            .collect()
+    }
+
+    /// Rasterizes printable ASCII, plus the printable Latin-1 supplement when
+    /// `include_latin1` is set, under `options` into both the atlas (editor and path text) and
+    /// the GPU scene glyph cache (labels), so the first typed or displayed Latin characters do
+    /// not stall on rasterization.
+    pub fn warm_atlas_latin_at_scale(
+        &mut self,
+        ctx: &Context,
+        scale: f32,
+        options: &TextLabelOptions,
+        include_latin1: bool,
+    ) {
+        let mut text: String = (' '..='~').collect();
+        if include_latin1 {
+            text.extend('\u{a1}'..='\u{ff}');
+        }
+        let options = core_label_options(options);
+        let layout = self.prepare_plain_text_layout(&text, &options, None, scale);
+        let _ = self.build_text_scene_from_layout(ctx, &layout, scale);
+        let graphics_config = self.resolved_graphics_config(self.max_texture_side_px);
+        for glyph in layout.glyphs.iter() {
+            let (raster_key, _) = glyph
+                .cache_key
+                .clone()
+                .quantized(graphics_config.rasterization.size_quantization_px);
+            let _ = self.get_or_rasterize_gpu_scene_glyph(
+                &raster_key,
+                graphics_config.rasterization,
+                graphics_config.atlas_padding_px,
+            );
+        }
+    }
+
     pub(crate) fn get_or_prepare_label_layout(
         &mut self,
         cache_id: Id,
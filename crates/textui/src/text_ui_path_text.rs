@@ -73,6 +73,61 @@ impl TextUi {
         )
     }
 
+    /// Per-glyph ink bounds, in layout order, computed from outlines without rasterizing.
+    /// Glyphs without an outline (spaces, bitmap-only faces) are skipped.
+    pub fn glyph_ink_bounds(
+        &mut self,
+        text: &str,
+        options: &TextLabelOptions,
+        width_points_opt: Option<f32>,
+    ) -> Vec<TextRect> {
+        let options = core_label_options(options);
+        let layout = self.prepare_plain_text_layout(text, &options, width_points_opt, 1.0);
+        let rasterization = self.graphics_config.rasterization;
+        layout
+            .glyphs
+            .iter()
+            .filter_map(|glyph| {
+                let cached = self
+                    .glyph_ink_bounds_cache
+                    .write(|state| state.touch(&glyph.cache_key).map(|entry| entry.value));
+                let bounds = cached.unwrap_or_else(|| {
+                    let bounds = render_swash_outline_commands(
+                        &mut self.font_system,
+                        &mut self.scale_context,
+                        &glyph.cache_key,
+                        rasterization,
+                    )
+                    .and_then(|commands| outline_bounds(&commands));
+                    self.glyph_ink_bounds_cache.write(|state| {
+                        let _ = state.insert(
+                            glyph.cache_key.clone(),
+                            bounds,
+                            mem::size_of::<(GlyphRasterKey, Option<Rect>)>(),
+                        );
+                    });
+                    bounds
+                })?;
+                Some(bounds.translate(glyph.offset_points).into())
+            })
+            .collect()
+    }
+
+    /// Tight bounds of the glyph outlines of `text`, ignoring advance-only space such as
+    /// trailing spaces and including italic overhang. `None` when nothing has ink.
+    pub fn ink_bounds(
+        &mut self,
+        text: &str,
+        options: &TextLabelOptions,
+        width_points_opt: Option<f32>,
+    ) -> Option<TextRect> {
+        self.glyph_ink_bounds(text, options, width_points_opt)
+            .into_iter()
+            .map(Rect::from)
+            .reduce(|bounds, glyph| bounds.union(glyph))
+            .map(TextRect::from)
+    }
+
     #[allow(dead_code)]
     pub(crate) fn paint_label_on_path(
         &mut self,
@@ -155,9 +210,10 @@ impl TextUi {
 
         for (glyph, path_glyph) in layout.glyphs.iter().zip(path_layout.glyphs.iter()) {
             let content_mode = self.resolved_glyph_content_mode(graphics_config, &glyph.cache_key);
-            let raster_key = glyph
+            let (raster_key, raster_scale) = glyph
                 .cache_key
-                .for_content_mode(content_mode, field_range_px);
+                .for_content_mode(content_mode, field_range_px)
+                .quantized(graphics_config.rasterization.size_quantization_px);
             let Some(atlas_entry) = self.glyph_atlas.resolve_or_queue(
                 painter.ctx(),
                 &mut self.font_system,
@@ -168,13 +224,18 @@ impl TextUi {
                 continue;
             };
 
-            let size_points = egui::vec2(
-                atlas_entry.size_px[0] as f32 / scale,
-                atlas_entry.size_px[1] as f32 / scale,
-            );
-            let origin_offset = egui::vec2(
-                atlas_entry.placement_left_px as f32 / scale,
-                -(atlas_entry.placement_top_px as f32) / scale,
+            let glyph_scale = scale / raster_scale;
+            let (origin_offset, size_points) = scale_color_glyph_box(
+                atlas_entry.is_color,
+                egui::vec2(
+                    atlas_entry.placement_left_px as f32 / glyph_scale,
+                    -(atlas_entry.placement_top_px as f32) / glyph_scale,
+                ),
+                egui::vec2(
+                    atlas_entry.size_px[0] as f32 / glyph_scale,
+                    atlas_entry.size_px[1] as f32 / glyph_scale,
+                ),
+                graphics_config.rasterization.color_glyph_scale,
             );
             let tint = if atlas_entry.is_color {
                 Color32::WHITE
@@ -116,6 +116,7 @@ impl TextUi {
                 .collect::<Vec<_>>();
             let default_attrs = default_attrs_owned.as_attrs();
             borrowed.set_rich_text(rich_text, &default_attrs, Shaping::Advanced, None);
+            set_buffer_justification(&mut borrowed, options.fundamentals.justify);
             borrowed.shape_until_scroll(true);
         }
 
@@ -147,12 +148,27 @@ impl TextUi {
     ) -> PreparedTextLayout {
         let mut effective_fundamentals = fundamentals.clone();
         effective_fundamentals.stem_darkening = stem_darkening;
-        let (glyphs, extra_width_points) = collect_prepared_glyphs_from_buffer(
+        if fundamentals.vertical {
+            let (mut glyphs, size_points) = collect_vertical_prepared_glyphs_from_buffer(
+                buffer,
+                scale,
+                default_color,
+                &effective_fundamentals,
+            );
+            self.mark_fallback_glyphs(&mut glyphs);
+            return PreparedTextLayout {
+                approx_bytes: glyphs.len().saturating_mul(mem::size_of::<PreparedGlyph>()),
+                glyphs: Arc::from(glyphs),
+                size_points,
+            };
+        }
+        let (mut glyphs, extra_width_points) = collect_prepared_glyphs_from_buffer(
             buffer,
             scale,
             default_color,
             &effective_fundamentals,
         );
+        self.mark_fallback_glyphs(&mut glyphs);
         let approx_bytes = glyphs.len().saturating_mul(mem::size_of::<PreparedGlyph>());
         PreparedTextLayout {
             glyphs: Arc::from(glyphs),
@@ -164,6 +180,17 @@ impl TextUi {
         }
     }
 
+    fn mark_fallback_glyphs(&self, glyphs: &mut [PreparedGlyph]) {
+        if !self.strict_fonts {
+            return;
+        }
+        for glyph in glyphs {
+            if !self.is_configured_font(glyph.cache_key.font_id()) {
+                glyph.color = STRICT_FONT_FALLBACK_COLOR;
+            }
+        }
+    }
+
     pub(crate) fn build_text_scene_from_layout(
         &mut self,
         ctx: &Context,
@@ -174,33 +201,63 @@ impl TextUi {
         let field_range_px = graphics_config.rasterization.field_range_px.max(1.0);
         let mut quads = Vec::with_capacity(layout.glyphs.len());
         let mut bounds: Option<Rect> = None;
+        let mut previous_glyph: Option<(&GlyphRasterKey, ResolvedGlyphAtlasEntry, f32)> = None;
 
         for glyph in layout.glyphs.iter() {
-            let content_mode = self.resolved_glyph_content_mode(graphics_config, &glyph.cache_key);
-            let raster_key = glyph
-                .cache_key
-                .for_content_mode(content_mode, field_range_px);
-            let Some(atlas_entry) = self.glyph_atlas.resolve_sync(
-                ctx,
-                &mut self.font_system,
-                &mut self.scale_context,
-                raster_key,
-                self.current_frame,
-            ) else {
-                continue;
+            // Runs of one glyph (rules, leaders, box drawing) reuse the previous atlas lookup and
+            // only translate the quad.
+            let (atlas_entry, raster_scale, atlas_hit) = match &previous_glyph {
+                Some((key, entry, raster_scale)) if **key == glyph.cache_key => {
+                    (entry.clone(), *raster_scale, true)
+                }
+                _ => {
+                    let content_mode =
+                        self.resolved_glyph_content_mode(graphics_config, &glyph.cache_key);
+                    let (raster_key, raster_scale) = glyph
+                        .cache_key
+                        .for_content_mode(content_mode, field_range_px)
+                        .quantized(graphics_config.rasterization.size_quantization_px);
+                    let atlas_hit =
+                        self.glyph_draw_log.is_some() && self.glyph_atlas.contains(&raster_key);
+                    let Some(atlas_entry) = self.glyph_atlas.resolve_sync(
+                        ctx,
+                        &mut self.font_system,
+                        &mut self.scale_context,
+                        raster_key,
+                        self.current_frame,
+                    ) else {
+                        previous_glyph = None;
+                        continue;
+                    };
+                    previous_glyph = Some((&glyph.cache_key, atlas_entry.clone(), raster_scale));
+                    (atlas_entry, raster_scale, atlas_hit)
+                }
             };
 
-            let min = Pos2::new(
-                glyph.offset_points.x + atlas_entry.placement_left_px as f32 / scale,
-                glyph.offset_points.y - atlas_entry.placement_top_px as f32 / scale,
-            );
-            let size_points = egui::vec2(
-                atlas_entry.size_px[0] as f32 / scale,
-                atlas_entry.size_px[1] as f32 / scale,
+            let glyph_scale = scale / raster_scale;
+            let (origin_offset, size_points) = scale_color_glyph_box(
+                atlas_entry.is_color,
+                egui::vec2(
+                    atlas_entry.placement_left_px as f32 / glyph_scale,
+                    -(atlas_entry.placement_top_px as f32) / glyph_scale,
+                ),
+                egui::vec2(
+                    atlas_entry.size_px[0] as f32 / glyph_scale,
+                    atlas_entry.size_px[1] as f32 / glyph_scale,
+                ),
+                graphics_config.rasterization.color_glyph_scale,
             );
+            let min = Pos2::new(glyph.offset_points.x, glyph.offset_points.y) + origin_offset;
             let positions = quad_positions_from_min_size(min, size_points);
             let quad_bounds = rect_from_points(positions);
             bounds = Some(bounds.map_or(quad_bounds, |current| current.union(quad_bounds)));
+            if let Some(log) = self.glyph_draw_log.as_mut() {
+                log.push(TextGlyphDrawInfo {
+                    rect: quad_bounds.into(),
+                    atlas_hit,
+                    atlas_page_index: atlas_entry.page_index,
+                });
+            }
             quads.push(TextAtlasQuad {
                 atlas_page_index: atlas_entry.page_index,
                 positions: positions.map(Into::into),
@@ -234,80 +291,142 @@ impl TextUi {
         let mut page_hashers = Vec::<FxHasher>::new();
         let mut quads = Vec::with_capacity(layout.glyphs.len());
         let mut bounds: Option<Rect> = None;
+        let mut has_color_glyphs = false;
+        let mut previous_glyph: Option<(
+            &GlyphRasterKey,
+            Arc<PreparedAtlasGlyph>,
+            usize,
+            Rect,
+            f32,
+        )> = None;
 
         for glyph in layout.glyphs.iter() {
-            let Some(atlas_glyph) = self.get_or_rasterize_gpu_scene_glyph(
-                &glyph.cache_key,
-                graphics_config.rasterization,
-                graphics_config.atlas_padding_px,
-            ) else {
-                continue;
+            // Runs of one glyph (rules, leaders, box drawing) reuse the previous page slot and
+            // only translate the quad.
+            let (atlas_glyph, page_index, uv, glyph_scale, atlas_hit) = match &previous_glyph {
+                Some((key, atlas_glyph, page_index, uv, glyph_scale))
+                    if **key == glyph.cache_key =>
+                {
+                    (
+                        Arc::clone(atlas_glyph),
+                        *page_index,
+                        *uv,
+                        *glyph_scale,
+                        true,
+                    )
+                }
+                _ => {
+                    let (raster_key, raster_scale) = glyph
+                        .cache_key
+                        .clone()
+                        .quantized(graphics_config.rasterization.size_quantization_px);
+                    let glyph_scale = scale / raster_scale;
+                    let atlas_hit = self.glyph_draw_log.is_some()
+                        && self
+                            .gpu_scene_glyph_cache
+                            .read(|state| state.contains_key(&raster_key));
+                    let Some(atlas_glyph) = self.get_or_rasterize_gpu_scene_glyph(
+                        &raster_key,
+                        graphics_config.rasterization,
+                        graphics_config.atlas_padding_px,
+                    ) else {
+                        previous_glyph = None;
+                        continue;
+                    };
+
+                    let allocation_size = size2(
+                        atlas_glyph.upload_image.size[0] as i32,
+                        atlas_glyph.upload_image.size[1] as i32,
+                    );
+                    let Some((page_index, allocation)) = allocate_cpu_scene_page_slot(
+                        &mut pages,
+                        &mut page_pool,
+                        target_page_side_px,
+                        allocation_size,
+                    ) else {
+                        previous_glyph = None;
+                        continue;
+                    };
+
+                    // Initialize hasher for any newly created pages.
+                    while page_hashers.len() < pages.len() {
+                        let new_idx = page_hashers.len();
+                        let mut h = FxHasher::default();
+                        new_idx.hash(&mut h);
+                        pages[new_idx].size.hash(&mut h);
+                        page_hashers.push(h);
+                    }
+
+                    let pos = [
+                        allocation.rectangle.min.x.max(0) as usize,
+                        allocation.rectangle.min.y.max(0) as usize,
+                    ];
+                    // Hash glyph identity + position — O(glyphs) instead of O(pixels) at
+                    // finalisation.
+                    glyph.cache_key.hash(&mut page_hashers[page_index]);
+                    pos[0].hash(&mut page_hashers[page_index]);
+                    pos[1].hash(&mut page_hashers[page_index]);
+
+                    let page_size = pages[page_index].size;
+                    blit_to_page(
+                        &mut pages[page_index].rgba8,
+                        page_size,
+                        &atlas_glyph.upload_image,
+                        pos[0],
+                        pos[1],
+                    );
+
+                    let uv = Rect::from_min_max(
+                        Pos2::new(
+                            (pos[0] + graphics_config.atlas_padding_px) as f32
+                                / page_size[0] as f32,
+                            (pos[1] + graphics_config.atlas_padding_px) as f32
+                                / page_size[1] as f32,
+                        ),
+                        Pos2::new(
+                            (pos[0] + graphics_config.atlas_padding_px + atlas_glyph.size_px[0])
+                                as f32
+                                / page_size[0] as f32,
+                            (pos[1] + graphics_config.atlas_padding_px + atlas_glyph.size_px[1])
+                                as f32
+                                / page_size[1] as f32,
+                        ),
+                    );
+                    previous_glyph = Some((
+                        &glyph.cache_key,
+                        Arc::clone(&atlas_glyph),
+                        page_index,
+                        uv,
+                        glyph_scale,
+                    ));
+                    (atlas_glyph, page_index, uv, glyph_scale, atlas_hit)
+                }
             };
 
-            let allocation_size = size2(
-                atlas_glyph.upload_image.size[0] as i32,
-                atlas_glyph.upload_image.size[1] as i32,
-            );
-            let Some((page_index, allocation)) = allocate_cpu_scene_page_slot(
-                &mut pages,
-                &mut page_pool,
-                target_page_side_px,
-                allocation_size,
-            ) else {
-                continue;
-            };
-
-            // Initialize hasher for any newly created pages.
-            while page_hashers.len() < pages.len() {
-                let new_idx = page_hashers.len();
-                let mut h = FxHasher::default();
-                new_idx.hash(&mut h);
-                pages[new_idx].size.hash(&mut h);
-                page_hashers.push(h);
-            }
-
-            let pos = [
-                allocation.rectangle.min.x.max(0) as usize,
-                allocation.rectangle.min.y.max(0) as usize,
-            ];
-            // Hash glyph identity + position — O(glyphs) instead of O(pixels) at finalisation.
-            glyph.cache_key.hash(&mut page_hashers[page_index]);
-            pos[0].hash(&mut page_hashers[page_index]);
-            pos[1].hash(&mut page_hashers[page_index]);
-
-            let page_size = pages[page_index].size;
-            blit_to_page(
-                &mut pages[page_index].rgba8,
-                page_size,
-                &atlas_glyph.upload_image,
-                pos[0],
-                pos[1],
-            );
-
-            let uv = Rect::from_min_max(
-                Pos2::new(
-                    (pos[0] + graphics_config.atlas_padding_px) as f32 / page_size[0] as f32,
-                    (pos[1] + graphics_config.atlas_padding_px) as f32 / page_size[1] as f32,
+            let (origin_offset, size_points) = scale_color_glyph_box(
+                atlas_glyph.is_color,
+                egui::vec2(
+                    atlas_glyph.placement_left_px as f32 / glyph_scale,
+                    -(atlas_glyph.placement_top_px as f32) / glyph_scale,
                 ),
-                Pos2::new(
-                    (pos[0] + graphics_config.atlas_padding_px + atlas_glyph.size_px[0]) as f32
-                        / page_size[0] as f32,
-                    (pos[1] + graphics_config.atlas_padding_px + atlas_glyph.size_px[1]) as f32
-                        / page_size[1] as f32,
+                egui::vec2(
+                    atlas_glyph.size_px[0] as f32 / glyph_scale,
+                    atlas_glyph.size_px[1] as f32 / glyph_scale,
                 ),
+                graphics_config.rasterization.color_glyph_scale,
             );
-
-            let min = Pos2::new(
-                glyph.offset_points.x + atlas_glyph.placement_left_px as f32 / scale,
-                glyph.offset_points.y - atlas_glyph.placement_top_px as f32 / scale,
-            );
-            let size_points = egui::vec2(
-                atlas_glyph.size_px[0] as f32 / scale,
-                atlas_glyph.size_px[1] as f32 / scale,
-            );
+            let min = Pos2::new(glyph.offset_points.x, glyph.offset_points.y) + origin_offset;
             let positions = quad_positions_from_min_size(min, size_points);
             let quad_bounds = rect_from_points(positions);
             bounds = Some(bounds.map_or(quad_bounds, |current| current.union(quad_bounds)));
+            if let Some(log) = self.glyph_draw_log.as_mut() {
+                log.push(TextGlyphDrawInfo {
+                    rect: quad_bounds.into(),
+                    atlas_hit,
+                    atlas_page_index: page_index,
+                });
+            }
+            has_color_glyphs |= atlas_glyph.is_color;
             quads.push(TextGpuQuad {
                 atlas_page_index: page_index,
                 positions: positions.map(|point| [point.x, point.y]),
@@ -362,6 +481,7 @@ impl TextUi {
             bounds_max: [bounds.max.x, bounds.max.y],
             size_points: [layout.size_points.x, layout.size_points.y],
             fingerprint: 0,
+            has_color_glyphs,
         }
     }
 
@@ -389,9 +509,10 @@ impl TextUi {
 
         for (glyph, path_glyph) in layout.glyphs.iter().zip(path_layout.glyphs.iter()) {
             let content_mode = self.resolved_glyph_content_mode(graphics_config, &glyph.cache_key);
-            let raster_key = glyph
+            let (raster_key, raster_scale) = glyph
                 .cache_key
-                .for_content_mode(content_mode, field_range_px);
+                .for_content_mode(content_mode, field_range_px)
+                .quantized(graphics_config.rasterization.size_quantization_px);
             let Some(atlas_entry) = self.glyph_atlas.resolve_sync(
                 ctx,
                 &mut self.font_system,
@@ -402,13 +523,18 @@ impl TextUi {
                 continue;
             };
 
-            let size_points = egui::vec2(
-                atlas_entry.size_px[0] as f32 / scale,
-                atlas_entry.size_px[1] as f32 / scale,
-            );
-            let origin_offset = egui::vec2(
-                atlas_entry.placement_left_px as f32 / scale,
-                -(atlas_entry.placement_top_px as f32) / scale,
+            let glyph_scale = scale / raster_scale;
+            let (origin_offset, size_points) = scale_color_glyph_box(
+                atlas_entry.is_color,
+                egui::vec2(
+                    atlas_entry.placement_left_px as f32 / glyph_scale,
+                    -(atlas_entry.placement_top_px as f32) / glyph_scale,
+                ),
+                egui::vec2(
+                    atlas_entry.size_px[0] as f32 / glyph_scale,
+                    atlas_entry.size_px[1] as f32 / glyph_scale,
+                ),
+                graphics_config.rasterization.color_glyph_scale,
             );
             let positions = rotated_quad_positions(
                 egui_point_from_text(path_glyph.anchor),
@@ -464,10 +590,16 @@ impl TextUi {
         let mut page_hashers = Vec::<FxHasher>::new();
         let mut quads = Vec::with_capacity(layout.glyphs.len());
         let mut bounds: Option<Rect> = None;
+        let mut has_color_glyphs = false;
 
         for (glyph, path_glyph) in layout.glyphs.iter().zip(path_layout.glyphs.iter()) {
+            let (raster_key, raster_scale) = glyph
+                .cache_key
+                .clone()
+                .quantized(graphics_config.rasterization.size_quantization_px);
+            let glyph_scale = scale / raster_scale;
             let Some(atlas_glyph) = self.get_or_rasterize_gpu_scene_glyph(
-                &glyph.cache_key,
+                &raster_key,
                 graphics_config.rasterization,
                 graphics_config.atlas_padding_px,
             ) else {
@@ -525,13 +657,17 @@ impl TextUi {
                 ),
             );
 
-            let size_points = egui::vec2(
-                atlas_glyph.size_px[0] as f32 / scale,
-                atlas_glyph.size_px[1] as f32 / scale,
-            );
-            let origin_offset = egui::vec2(
-                atlas_glyph.placement_left_px as f32 / scale,
-                -(atlas_glyph.placement_top_px as f32) / scale,
+            let (origin_offset, size_points) = scale_color_glyph_box(
+                atlas_glyph.is_color,
+                egui::vec2(
+                    atlas_glyph.placement_left_px as f32 / glyph_scale,
+                    -(atlas_glyph.placement_top_px as f32) / glyph_scale,
+                ),
+                egui::vec2(
+                    atlas_glyph.size_px[0] as f32 / glyph_scale,
+                    atlas_glyph.size_px[1] as f32 / glyph_scale,
+                ),
+                graphics_config.rasterization.color_glyph_scale,
             );
             let positions = rotated_quad_positions(
                 egui_point_from_text(path_glyph.anchor),
@@ -541,6 +677,7 @@ impl TextUi {
             );
             let quad_bounds = rect_from_points(positions);
             bounds = Some(bounds.map_or(quad_bounds, |current| current.union(quad_bounds)));
+            has_color_glyphs |= atlas_glyph.is_color;
             quads.push(TextGpuQuad {
                 atlas_page_index: page_index,
                 positions: positions.map(|point| [point.x, point.y]),
@@ -594,6 +731,7 @@ impl TextUi {
             bounds_max: [bounds.max.x, bounds.max.y],
             size_points: [layout.size_points.x, layout.size_points.y],
             fingerprint: 0,
+            has_color_glyphs,
         })
     }
 
@@ -651,11 +789,13 @@ impl TextUi {
         rasterization: TextRasterizationConfig,
         padding_px: usize,
     ) -> Option<Arc<PreparedAtlasGlyph>> {
+        // Glyphs under `min_mask_coverage` stay cached as placeholders so they are not
+        // rasterized again, but are never handed out for drawing.
         if let Some(glyph) = self
             .gpu_scene_glyph_cache
             .write(|state| state.touch(cache_key).map(|entry| Arc::clone(&entry.value)))
         {
-            return Some(glyph);
+            return (!glyph.is_below_min_coverage()).then_some(glyph);
         }
 
         let glyph = Arc::new(rasterize_atlas_glyph(
@@ -665,9 +805,10 @@ impl TextUi {
             rasterization,
             padding_px,
         )?);
+        self.glyph_atlas.record_glyph_size(glyph.size_px);
         self.gpu_scene_glyph_cache.write(|state| {
             let _ = state.insert(cache_key.clone(), Arc::clone(&glyph), glyph.approx_bytes);
         });
-        Some(glyph)
+        (!glyph.is_below_min_coverage()).then_some(glyph)
     }
 }
@@ -1,5 +1,5 @@
 use egui::{Color32, Stroke, Vec2};
-use textui::{EguiInputOptions, TextFundamentals};
+use textui::{EguiInputOptions, TextCaretHeight, TextFundamentals};
 
 #[derive(Clone, Debug)]
 pub struct InputOptions {
@@ -23,6 +23,20 @@ pub struct InputOptions {
     pub desired_rows: usize,
     pub placeholder_text: Option<String>,
     pub placeholder_color: Option<Color32>,
+    /// Width in points over which glyphs fade out as they scroll past the clip edge. `0.0`
+    /// keeps the hard clip.
+    pub clip_fade_points: f32,
+    /// Debug overlay: draws a hairline at every visible line's baseline in this color.
+    pub baseline_debug_color: Option<Color32>,
+    /// Debug overlay: strokes the painted rect of every glyph quad in this color.
+    pub glyph_box_debug_color: Option<Color32>,
+    /// Draws this stroke under the text at every visible line's baseline, e.g. the focus
+    /// stroke for underline-style fields.
+    pub baseline_stroke: Option<Stroke>,
+    /// Snaps each glyph's origin (not its size) to the device-pixel grid so the text lines up
+    /// with surrounding egui widgets. Off by default, which keeps subpixel glyph positions.
+    pub align_to_egui_grid: bool,
+    pub caret_height: TextCaretHeight,
     pub fundamentals: TextFundamentals,
 }
 
@@ -49,6 +63,12 @@ impl Default for InputOptions {
             desired_rows: 5,
             placeholder_text: None,
             placeholder_color: None,
+            clip_fade_points: 0.0,
+            baseline_debug_color: None,
+            glyph_box_debug_color: None,
+            baseline_stroke: None,
+            align_to_egui_grid: false,
+            caret_height: TextCaretHeight::LineHeight,
             fundamentals: TextFundamentals::default(),
         }
     }
@@ -77,6 +97,12 @@ impl InputOptions {
             desired_rows: self.desired_rows,
             placeholder_text: self.placeholder_text.clone(),
             placeholder_color: self.placeholder_color,
+            clip_fade_points: self.clip_fade_points,
+            baseline_debug_color: self.baseline_debug_color,
+            glyph_box_debug_color: self.glyph_box_debug_color,
+            baseline_stroke: self.baseline_stroke,
+            align_to_egui_grid: self.align_to_egui_grid,
+            caret_height: self.caret_height,
             fundamentals: self.fundamentals.clone(),
         }
     }
@@ -0,0 +1,54 @@
+use egui::{
+    FontFamily, FontId,
+    text::{LayoutJob, TextFormat},
+};
+use textui::{RichTextSpan, RichTextStyle};
+
+use crate::LabelOptions;
+
+/// Line height egui's default fonts roughly produce, as a multiple of the font size, used
+/// when a format does not set one.
+const EGUI_DEFAULT_LINE_HEIGHT_FACTOR: f32 = 1.5;
+
+/// Label options matching an egui [`FontId`]. egui has no weight axis, so the weight stays at
+/// the default; any [`FontFamily::Name`] family maps to the proportional UI font.
+pub fn label_options_from_font_id(font_id: &FontId) -> LabelOptions {
+    LabelOptions {
+        font_size: font_id.size,
+        line_height: font_id.size * EGUI_DEFAULT_LINE_HEIGHT_FACTOR,
+        monospace: font_id.family == FontFamily::Monospace,
+        ..LabelOptions::default()
+    }
+}
+
+/// Label options matching an egui [`TextFormat`], for porting code that styles galleys.
+pub fn label_options_from_text_format(format: &TextFormat) -> LabelOptions {
+    let mut options = label_options_from_font_id(&format.font_id);
+    if let Some(line_height) = format.line_height {
+        options.line_height = line_height;
+    }
+    options.color = format.color;
+    options.italic = format.italics;
+    options
+}
+
+pub fn rich_text_style_from_text_format(format: &TextFormat) -> RichTextStyle {
+    RichTextStyle {
+        color: format.color.into(),
+        monospace: format.font_id.family == FontFamily::Monospace,
+        italic: format.italics,
+        ..RichTextStyle::default()
+    }
+}
+
+/// Splits an egui [`LayoutJob`] into rich-text spans, one per section. Font sizes are not
+/// per-span in textui; take them from [`label_options_from_text_format`] on the first section.
+pub fn rich_text_spans_from_layout_job(job: &LayoutJob) -> Vec<RichTextSpan> {
+    job.sections
+        .iter()
+        .map(|section| RichTextSpan {
+            text: job.text[section.byte_range.clone()].to_owned(),
+            style: rich_text_style_from_text_format(&section.format),
+        })
+        .collect()
+}
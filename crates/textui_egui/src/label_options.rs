@@ -15,6 +15,9 @@ pub struct LabelOptions {
     /// The string appended when text is truncated.  Defaults to the Unicode
     /// ellipsis character (U+2026).
     pub ellipsis: String,
+    /// When set on a non-wrapping label, text wider than the available width is cut and
+    /// ends in [`Self::ellipsis`]; if not even the ellipsis fits, nothing is drawn.
+    pub truncate: bool,
 }
 
 impl LabelOptions {
@@ -47,6 +50,7 @@ impl Default for LabelOptions {
             padding: egui::vec2(0.0, 0.0),
             fundamentals: TextFundamentals::default(),
             ellipsis: DEFAULT_ELLIPSIS.to_owned(),
+            truncate: false,
         }
     }
 }
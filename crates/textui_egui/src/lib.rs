@@ -1,14 +1,15 @@
 mod button_options;
 mod code_block_options;
 mod input_options;
+mod interop;
 mod label_options;
 mod markdown_options;
 mod text_helpers;
 mod tooltip_options;
 
 use egui::{
-    Color32, Context, CornerRadius, Id, Painter, Rect, Response, Sense, TextureHandle, TextureId,
-    TextureOptions, Ui, Vec2,
+    Color32, Context, CornerRadius, Id, Painter, Pos2, Rect, Response, Sense, TextureHandle,
+    TextureId, TextureOptions, Ui, Vec2, emath::TSTransform,
 };
 use egui_wgpu::RenderState;
 use std::{
@@ -26,6 +27,10 @@ use textui::{
 pub use button_options::ButtonOptions;
 pub use code_block_options::CodeBlockOptions;
 pub use input_options::InputOptions;
+pub use interop::{
+    label_options_from_font_id, label_options_from_text_format, rich_text_spans_from_layout_job,
+    rich_text_style_from_text_format,
+};
 pub use label_options::LabelOptions;
 pub use markdown_options::MarkdownOptions;
 pub use text_helpers::{
@@ -52,6 +57,12 @@ impl TextTextureHandle {
         self.scene
     }
 
+    /// Whether the prepared text draws any color (emoji) glyphs, for warning before handing
+    /// it to an export path that only understands monochrome text.
+    pub fn has_color_glyphs(&self) -> bool {
+        self.scene.has_color_glyphs
+    }
+
     pub fn paint(&self, text_ui: &mut TextUi, ui: &Ui, rect: Rect) {
         let painter = ui.painter().with_clip_rect(ui.clip_rect());
         paint_gpu_scene_in_rect(text_ui, &painter, rect, &self.scene, Color32::WHITE);
@@ -71,8 +82,104 @@ impl TextTextureHandle {
     ) {
         paint_gpu_scene_in_rect(text_ui, painter, rect, &self.scene, tint);
     }
+
+    /// Paints into `document_rect`, a rect in document space mapped to the screen through
+    /// `transform` (e.g. a pan/zoom camera). Visibility is tested in document space against
+    /// the painter clip mapped back through the inverse transform, so the camera never has to
+    /// be folded into the clip rect.
+    pub fn paint_transformed(
+        &self,
+        text_ui: &mut TextUi,
+        painter: &egui::Painter,
+        document_rect: Rect,
+        transform: TSTransform,
+        tint: egui::Color32,
+    ) {
+        let document_clip = transform.inverse() * painter.clip_rect();
+        if !document_clip.intersects(document_rect) {
+            return;
+        }
+        paint_gpu_scene_in_rect(
+            text_ui,
+            painter,
+            transform * document_rect,
+            &self.scene,
+            tint,
+        );
+    }
+
+    /// Paints into `rect` like [`Self::paint_on`] but clips to `rect` shrunk by
+    /// `clip_padding` points, so glyphs stop short of a border. Layout still starts at
+    /// `rect`; only the clip tightens.
+    pub fn paint_with_clip_padding(
+        &self,
+        text_ui: &mut TextUi,
+        painter: &egui::Painter,
+        rect: Rect,
+        clip_padding: f32,
+        tint: egui::Color32,
+    ) {
+        let clip_rect = rect
+            .shrink(clip_padding.max(0.0))
+            .intersect(painter.clip_rect());
+        if !clip_rect.is_positive() {
+            return;
+        }
+        let painter = painter.with_clip_rect(clip_rect);
+        paint_gpu_scene_in_rect(text_ui, &painter, rect, &self.scene, tint);
+    }
+
+    /// Composites the whole scene on the CPU over `background` into a texture of its own,
+    /// sized to the text at the context's pixel density, and returns it with the size in
+    /// points. Useful for caching a label as an image or handing it to code that only takes
+    /// textures; the result does not track later atlas changes.
+    pub fn rasterize_to_texture(
+        &self,
+        ctx: &Context,
+        background: Color32,
+    ) -> (TextureHandle, Vec2) {
+        let image = composite_gpu_scene(&self.scene, ctx.pixels_per_point(), background);
+        let handle = ctx.load_texture(
+            format!("textui_egui_rasterized_{:016x}", self.scene.fingerprint),
+            image,
+            TextureOptions::LINEAR,
+        );
+        (handle, self.size_points)
+    }
+
+    /// Paints the text scaled down uniformly (never up) so it fits inside `rect`, centred on
+    /// it, and returns the rect actually painted. The atlas texture is stretched rather than
+    /// re-shaped, so heavy downscaling softens glyphs; prepare the text at a smaller font size
+    /// when that matters.
+    pub fn paint_fit(
+        &self,
+        text_ui: &mut TextUi,
+        painter: &egui::Painter,
+        rect: Rect,
+        tint: egui::Color32,
+    ) -> Rect {
+        let size = self.size_points;
+        let fit_scale = if size.x > 0.0 && size.y > 0.0 {
+            (rect.width() / size.x).min(rect.height() / size.y).min(1.0)
+        } else {
+            1.0
+        };
+        let fitted_rect = Rect::from_center_size(rect.center(), size * fit_scale.max(0.0));
+        paint_gpu_scene_in_rect(text_ui, painter, fitted_rect, &self.scene, tint);
+        fitted_rect
+    }
 }
 
+/// Quads painted versus skipped by the clip test in the egui mesh paint path since the last
+/// [`begin_frame`]. Mostly-drawn counts while scrolling long content point at a clip rect
+/// that is larger than the viewport.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct PaintCullStats {
+    pub drawn_quads: usize,
+    pub culled_quads: usize,
+}
+
+const PAINT_CULL_STATS_ID: &str = "textui_egui_paint_cull_stats";
 const GPU_SCENE_TEXTURE_CACHE_ID: &str = "textui_egui_gpu_scene_texture_cache";
 const GPU_SCENE_TEXTURE_CACHE_STALE_FRAMES: u64 = 600;
 const RETAINED_GPU_SCENE_CACHE_ID: &str = "textui_egui_retained_gpu_scene_cache";
@@ -220,6 +327,9 @@ fn hash_text_fundamentals(hasher: &mut DefaultHasher, fundamentals: &textui::Tex
     fundamentals.letter_spacing_points.to_bits().hash(hasher);
     fundamentals.word_spacing_points.to_bits().hash(hasher);
     fundamentals.letter_spacing_floor.to_bits().hash(hasher);
+    fundamentals.justify.hash(hasher);
+    fundamentals.vertical.hash(hasher);
+    fundamentals.hinting.hash(hasher);
     fundamentals.feature_settings.hash(hasher);
     fundamentals.variation_settings.hash(hasher);
 }
@@ -236,6 +346,7 @@ fn hash_label_options(hasher: &mut DefaultHasher, options: &LabelOptions) {
     options.padding.y.to_bits().hash(hasher);
     hash_text_fundamentals(hasher, &options.fundamentals);
     options.ellipsis.hash(hasher);
+    options.truncate.hash(hasher);
 }
 
 fn hash_label_scene_request(
@@ -507,7 +618,19 @@ fn paint_gpu_scene_impl(
     tint: Color32,
     transform: Option<&PaintTransform>,
 ) {
+    let clip_rect = painter.clip_rect();
+    // A NaN or inverted clip makes the cull test below meaningless. Inverted clips also come
+    // from intersecting disjoint rects (scrolled-out content), so this only logs at debug.
+    if clip_rect.any_nan() || clip_rect.is_negative() {
+        tracing::debug!(
+            target: "vertexlauncher/textui_egui",
+            "skipping text paint with degenerate clip rect {clip_rect:?}"
+        );
+        return;
+    }
     let texture_ids = texture_ids_for_gpu_scene(text_ui, painter.ctx(), scene);
+    let mut drawn_quads = 0;
+    let mut culled_quads = 0;
     let draw_options = if let Some(t) = transform {
         textui::TextGpuSceneDrawOptions {
             offset: textui::TextPoint::new(t.offset[0], t.offset[1]),
@@ -531,6 +654,11 @@ fn paint_gpu_scene_impl(
         let mut mesh = egui::epaint::Mesh::with_texture(texture_id);
         for quad in batch.quads.iter() {
             let positions = quad.positions.map(|point| egui::pos2(point[0], point[1]));
+            if !clip_rect.intersects(Rect::from_points(&positions)) {
+                culled_quads += 1;
+                continue;
+            }
+            drawn_quads += 1;
             let uvs = quad.uvs.map(|point| egui::pos2(point[0], point[1]));
             let final_tint = Color32::from_rgba_premultiplied(
                 quad.tint_rgba[0],
@@ -544,6 +672,17 @@ fn paint_gpu_scene_impl(
             painter.add(egui::Shape::mesh(mesh));
         }
     }
+    painter.ctx().data_mut(|data| {
+        let stats = data.get_temp_mut_or_default::<PaintCullStats>(Id::new(PAINT_CULL_STATS_ID));
+        stats.drawn_quads += drawn_quads;
+        stats.culled_quads += culled_quads;
+    });
+}
+
+/// Quads drawn and culled this frame, counting only the mesh paint path; reset in [`begin_frame`].
+pub fn paint_cull_stats(ctx: &Context) -> PaintCullStats {
+    ctx.data(|data| data.get_temp::<PaintCullStats>(Id::new(PAINT_CULL_STATS_ID)))
+        .unwrap_or_default()
 }
 
 fn paint_gpu_scene_absolute(
@@ -555,6 +694,72 @@ fn paint_gpu_scene_absolute(
     paint_gpu_scene_impl(text_ui, painter, scene, tint, None);
 }
 
+/// Software source-over composite of `scene` at `scale` pixels per point. Label scenes only
+/// contain axis-aligned quads, so each quad maps its uv rect onto its pixel rect with nearest
+/// sampling; atlas texels and tints are both premultiplied.
+fn composite_gpu_scene(scene: &TextGpuScene, scale: f32, background: Color32) -> egui::ColorImage {
+    let scale = scale.max(f32::EPSILON);
+    let size = [
+        (scene.size_points[0] * scale).ceil().max(1.0) as usize,
+        (scene.size_points[1] * scale).ceil().max(1.0) as usize,
+    ];
+    let mut image = egui::ColorImage::filled(size, background);
+    let pages: HashMap<usize, &TextAtlasPageData> = scene
+        .atlas_pages
+        .iter()
+        .map(|page| (page.page_index, page))
+        .collect();
+    for quad in &scene.quads {
+        let Some(page) = pages.get(&quad.atlas_page_index) else {
+            continue;
+        };
+        let [min, _, max, _] = quad.positions;
+        let [uv_min, _, uv_max, _] = quad.uvs;
+        let (x0, y0) = ((min[0] * scale).floor(), (min[1] * scale).floor());
+        let (x1, y1) = ((max[0] * scale).ceil(), (max[1] * scale).ceil());
+        let (width, height) = ((max[0] - min[0]) * scale, (max[1] - min[1]) * scale);
+        if width <= 0.0 || height <= 0.0 {
+            continue;
+        }
+        for y in (y0.max(0.0) as usize)..(y1.max(0.0) as usize).min(size[1]) {
+            let v = (y as f32 + 0.5 - min[1] * scale) / height;
+            if !(0.0..1.0).contains(&v) {
+                continue;
+            }
+            let texel_y = ((uv_min[1] + (uv_max[1] - uv_min[1]) * v) * page.size_px[1] as f32)
+                .clamp(0.0, page.size_px[1].saturating_sub(1) as f32)
+                as usize;
+            for x in (x0.max(0.0) as usize)..(x1.max(0.0) as usize).min(size[0]) {
+                let u = (x as f32 + 0.5 - min[0] * scale) / width;
+                if !(0.0..1.0).contains(&u) {
+                    continue;
+                }
+                let texel_x = ((uv_min[0] + (uv_max[0] - uv_min[0]) * u) * page.size_px[0] as f32)
+                    .clamp(0.0, page.size_px[0].saturating_sub(1) as f32)
+                    as usize;
+                let offset = (texel_y * page.size_px[0] + texel_x) * 4;
+                let Some(texel) = page.rgba8.get(offset..offset + 4) else {
+                    continue;
+                };
+                let src: [u16; 4] = std::array::from_fn(|channel| {
+                    (u16::from(texel[channel]) * u16::from(quad.tint_rgba[channel]) + 127) / 255
+                });
+                let dst = &mut image.pixels[y * size[0] + x];
+                let under = dst.to_array();
+                let inv_alpha = 255 - src[3];
+                let blended: [u8; 4] = std::array::from_fn(|channel| {
+                    (src[channel] + (u16::from(under[channel]) * inv_alpha + 127) / 255).min(255)
+                        as u8
+                });
+                *dst = Color32::from_rgba_premultiplied(
+                    blended[0], blended[1], blended[2], blended[3],
+                );
+            }
+        }
+    }
+    image
+}
+
 fn paint_gpu_scene_in_rect(
     text_ui: &TextUi,
     painter: &Painter,
@@ -604,6 +809,25 @@ pub trait TextUiEguiExt {
         text: &str,
         options: &LabelOptions,
     ) -> Response;
+    /// Allocates, paints and senses a label like [`Self::clickable_label`], with the caller's
+    /// `sense` (e.g. `Sense::click_and_drag()` or `Sense::focusable_noninteractive()`).
+    fn label_with_sense<H: Hash>(
+        &mut self,
+        ui: &mut Ui,
+        id_source: H,
+        text: &str,
+        options: &LabelOptions,
+        sense: Sense,
+    ) -> Response;
+    /// Lays out differently styled spans as one label, for the common "a few colored or bold
+    /// segments" case without hand-building a rich text layout.
+    fn rich_label<H: Hash>(
+        &mut self,
+        ui: &mut Ui,
+        id_source: H,
+        spans: &[RichTextSpan],
+        options: &LabelOptions,
+    ) -> Response;
     fn measure_text_size(&mut self, ui: &Ui, text: &str, options: &LabelOptions) -> Vec2;
     fn prepare_label_texture<H: Hash>(
         &mut self,
@@ -621,6 +845,20 @@ pub trait TextUiEguiExt {
         options: &LabelOptions,
         width_points_opt: Option<f32>,
     ) -> TextTextureHandle;
+    /// Paints a label with its top-left corner at `pos` and returns the rect it occupies,
+    /// taken from the same shaping pass that built the scene, so no separate measure call is
+    /// needed. The rect is in the painter's coordinates (the same space as
+    /// [`Painter::clip_rect`]) after pixel snapping, so it can be reused directly as the clip
+    /// or interaction rect of a following overlay.
+    fn paint_label_measured<H: Hash>(
+        &mut self,
+        painter: &Painter,
+        id_source: H,
+        text: &str,
+        options: &LabelOptions,
+        pos: Pos2,
+        width_points_opt: Option<f32>,
+    ) -> Rect;
     fn paint_label_on_path<H: Hash>(
         &mut self,
         painter: &Painter,
@@ -711,6 +949,49 @@ pub trait TextUiEguiExt {
     ) -> Response;
 }
 
+/// Ellipsis-truncated label text for `max_width`, cached per label so steady frames skip the
+/// reshaping. Text that already fits is returned unchanged, whitespace included.
+fn truncated_label_text(
+    text_ui: &mut TextUi,
+    ui: &Ui,
+    id_source: impl Hash,
+    text: &str,
+    options: &LabelOptions,
+    max_width: f32,
+) -> Arc<str> {
+    let scale = ui.ctx().pixels_per_point();
+    let mut hasher = DefaultHasher::new();
+    "label_truncation".hash(&mut hasher);
+    text.hash(&mut hasher);
+    hash_label_options(&mut hasher, options);
+    max_width.to_bits().hash(&mut hasher);
+    scale.to_bits().hash(&mut hasher);
+    let fingerprint = hasher.finish();
+
+    let cache_id = ui.make_persistent_id((id_source, "textui_label_truncation"));
+    if let Some((cached_fingerprint, truncated)) =
+        ui.data(|data| data.get_temp::<(u64, Arc<str>)>(cache_id))
+        && cached_fingerprint == fingerprint
+    {
+        return truncated;
+    }
+
+    let full_width = text_ui
+        .measure_text_size_at_scale(scale, text, &options.to_text_label_options())
+        .x;
+    let truncated: Arc<str> = if full_width <= max_width {
+        Arc::from(text)
+    } else {
+        Arc::from(
+            truncate_single_line_text_with_ellipsis_preserving_whitespace(
+                text_ui, ui, text, max_width, options,
+            ),
+        )
+    };
+    ui.data_mut(|data| data.insert_temp(cache_id, (fingerprint, Arc::clone(&truncated))));
+    truncated
+}
+
 fn label_impl(
     text_ui: &mut TextUi,
     ui: &mut Ui,
@@ -728,6 +1009,20 @@ fn label_impl(
     } else {
         text
     };
+    let truncated_text;
+    let text = if options.truncate && !options.wrap {
+        truncated_text = truncated_label_text(
+            text_ui,
+            ui,
+            &id_source,
+            text,
+            options,
+            ui.available_width() - options.padding.x * 2.0,
+        );
+        &*truncated_text
+    } else {
+        text
+    };
 
     let scale = ui.ctx().pixels_per_point();
     let width_points_opt =
@@ -1152,6 +1447,42 @@ impl TextUiEguiExt for TextUi {
         label_impl(self, ui, id_source, text, options, Sense::click(), false)
     }
 
+    fn label_with_sense<H: Hash>(
+        &mut self,
+        ui: &mut Ui,
+        id_source: H,
+        text: &str,
+        options: &LabelOptions,
+        sense: Sense,
+    ) -> Response {
+        label_impl(self, ui, id_source, text, options, sense, false)
+    }
+
+    fn rich_label<H: Hash>(
+        &mut self,
+        ui: &mut Ui,
+        id_source: H,
+        spans: &[RichTextSpan],
+        options: &LabelOptions,
+    ) -> Response {
+        let width_points_opt = options.wrap.then(|| ui.available_width().max(1.0));
+        let handle = self.prepare_rich_text_texture(
+            ui.ctx(),
+            ui.make_persistent_id(id_source),
+            spans,
+            options,
+            width_points_opt,
+        );
+        let (rect, response) =
+            ui.allocate_exact_size(handle.size_points + options.padding * 2.0, Sense::hover());
+        handle.paint(
+            self,
+            ui,
+            Rect::from_min_size(rect.min + options.padding, handle.size_points),
+        );
+        response
+    }
+
     fn measure_text_size(&mut self, ui: &Ui, text: &str, options: &LabelOptions) -> Vec2 {
         self.measure_text_size_at_scale(
             ui.ctx().pixels_per_point(),
@@ -1225,6 +1556,22 @@ impl TextUiEguiExt for TextUi {
         }
     }
 
+    fn paint_label_measured<H: Hash>(
+        &mut self,
+        painter: &Painter,
+        id_source: H,
+        text: &str,
+        options: &LabelOptions,
+        pos: Pos2,
+        width_points_opt: Option<f32>,
+    ) -> Rect {
+        let handle =
+            self.prepare_label_texture(painter.ctx(), id_source, text, options, width_points_opt);
+        let rect = Rect::from_min_size(pos, handle.size_points);
+        handle.paint_on(self, painter, rect, Color32::WHITE);
+        snap_rect_to_pixel_grid(rect, painter.pixels_per_point())
+    }
+
     fn paint_label_on_path<H: Hash>(
         &mut self,
         painter: &Painter,
@@ -1439,6 +1786,9 @@ pub fn begin_frame(
     render_state: Option<&RenderState>,
 ) -> TextFrameOutput {
     text_ui.egui_set_render_state(render_state);
+    ctx.data_mut(|data| {
+        data.insert_temp(Id::new(PAINT_CULL_STATS_ID), PaintCullStats::default());
+    });
     text_ui.begin_frame_info(TextFrameInfo::new(
         ctx.cumulative_frame_nr(),
         ctx.input(|i| i.max_texture_side).max(1),
@@ -1447,6 +1797,18 @@ pub fn begin_frame(
     text_ui.egui_flush_frame(ctx)
 }
 
+/// Counterpart of [`begin_frame`] for apps that enable
+/// [`TextUi::set_atlas_upload_coalescing`]; call it once all text for the frame is painted.
+pub fn end_frame(text_ui: &mut TextUi) {
+    flush_atlas_uploads(text_ui);
+}
+
+/// Uploads glyph regions deferred by upload coalescing right away, e.g. before a mid-frame
+/// screenshot or texture readback. [`end_frame`] does the same at the end of the frame.
+pub fn flush_atlas_uploads(text_ui: &mut TextUi) {
+    text_ui.egui_flush_atlas_uploads();
+}
+
 pub fn set_gamepad_scroll_delta(ctx: &Context, delta: Vec2) {
     ctx.data_mut(|data| data.insert_temp(Id::new(GAMEPAD_SCROLL_DELTA_ID), delta));
 }